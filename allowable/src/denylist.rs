@@ -0,0 +1,43 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::Allowable;
+
+// Mode picks whether the list names the only addresses allowed or the ones
+// denied
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Mode {
+    #[default]
+    Allowlist,
+    Denylist,
+}
+
+impl<'a> Allowable<'a> {
+    pub fn get_mode(&self, deps: &Deps) -> StdResult<Mode> {
+        Ok(self.mode.may_load(deps.storage)?.unwrap_or_default())
+    }
+
+    pub fn set_mode(&self, deps: &mut DepsMut, mode: Mode) -> StdResult<()> {
+        self.mode.save(deps.storage, &mode)
+    }
+
+    pub fn deny_addrs(&self, deps: &mut DepsMut, denied_addrs: Vec<Addr>) -> StdResult<()> {
+        for addr in denied_addrs {
+            self.denied_addrs.save(deps.storage, addr, &true)?;
+        }
+        Ok(())
+    }
+
+    pub fn remove_denied_addrs(&self, deps: &mut DepsMut, addrs: Vec<Addr>) -> StdResult<()> {
+        for addr in addrs {
+            self.denied_addrs.remove(deps.storage, addr);
+        }
+        Ok(())
+    }
+
+    pub fn is_denied_addr(&self, deps: &Deps, addr: Addr) -> StdResult<bool> {
+        Ok(self.denied_addrs.has(deps.storage, addr))
+    }
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Timestamp};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -9,6 +9,24 @@ pub enum AllowableError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Ownership renounced, allowlist is immutable")]
+    OwnershipRenounced {},
+
+    #[error("Address is not allowed")]
+    NotAllowed {},
+
+    #[error("Quota exceeded, {remaining} items remaining")]
+    QuotaExceeded { remaining: u32 },
+
+    #[error("Invalid window, starts at {start} but ends at {end}")]
+    InvalidWindow { start: Timestamp, end: Timestamp },
+
+    #[error("Invalid merkle root {root}")]
+    InvalidMerkleRoot { root: String },
+
+    #[error("Invalid merkle proof node {node}")]
+    InvalidMerkleProof { node: String },
+
     // #[error("{0}")]
     // SerdeJson(#[from] serde_json::Error),
     #[error("Custom Error val: {val:?}")]
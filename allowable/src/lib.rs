@@ -1,32 +1,67 @@
+pub mod denylist;
 mod errors;
+pub mod merkle;
 mod msg;
+mod quotas;
 mod state;
 mod test;
+pub mod windows;
 
-use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, StdResult};
+use cosmwasm_std::{
+    Addr, Deps, DepsMut, Env, MessageInfo, Order, OverflowError, OverflowOperation, StdError,
+    StdResult, Timestamp,
+};
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use crate::denylist::Mode;
 use crate::errors::AllowableError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, QueryResp};
-use crate::state::{ALLOWED_ADDRS, ENABLED};
+use crate::msg::{AllowedEntry, ExecuteMsg, InstantiateMsg, QueryMsg, QueryResp};
+use crate::state::{
+    ALLOWED_ADDRS, ALLOWED_COUNT, DEFAULT_WINDOW, DENIED_ADDRS, ENABLED, MERKLE_ROOT, MODE, QUOTAS,
+    QUOTA_USED, WINDOWS,
+};
+use crate::windows::Window;
 use burnt_glue::module::Module;
 use burnt_glue::response::Response;
-use cw_storage_plus::{Item, Map};
+use cw_storage_plus::{Bound, Item, Map};
+use ownable::roles::{Role, Roles, ROLE_MEMBERS};
 use ownable::Ownable;
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
 pub struct Allowable<'a> {
-    ownable: Rc<RefCell<Ownable<'a>>>,
+    pub ownable: Rc<RefCell<Ownable<'a>>>,
+    pub roles: Rc<RefCell<Roles<'a>>>,
     pub allowed_addrs: Map<'a, Addr, bool>,
+    pub allowed_count: Item<'a, u64>,
     pub enabled: Item<'a, bool>,
+    pub merkle_root: Item<'a, String>,
+    pub quotas: Map<'a, Addr, u32>,
+    pub quota_used: Map<'a, Addr, u32>,
+    pub windows: Map<'a, Addr, Window>,
+    pub default_window: Item<'a, Window>,
+    pub mode: Item<'a, Mode>,
+    pub denied_addrs: Map<'a, Addr, bool>,
 }
 
 impl<'a> Default for Allowable<'a> {
     fn default() -> Self {
+        let ownable = Rc::new(RefCell::new(Ownable::default()));
         Self {
-            ownable: Rc::new(RefCell::new(Ownable::default())),
+            roles: Rc::new(RefCell::new(Roles::new(ROLE_MEMBERS, ownable.clone()))),
+            ownable,
             allowed_addrs: ALLOWED_ADDRS,
+            allowed_count: ALLOWED_COUNT,
             enabled: ENABLED,
+            merkle_root: MERKLE_ROOT,
+            quotas: QUOTAS,
+            quota_used: QUOTA_USED,
+            windows: WINDOWS,
+            default_window: DEFAULT_WINDOW,
+            mode: MODE,
+            denied_addrs: DENIED_ADDRS,
         }
     }
 }
@@ -41,33 +76,97 @@ impl<'a> Allowable<'a> {
     }
 
     pub fn allow_addrs(&self, deps: &mut DepsMut, allowed_addrs: Vec<Addr>) -> StdResult<()> {
+        let mut count = self.count(&deps.as_ref())?;
         for addr in allowed_addrs {
+            if !self.allowed_addrs.has(deps.storage, addr.clone()) {
+                count += 1;
+            }
             self.allowed_addrs.save(deps.storage, addr, &true)?;
         }
-        Ok(())
+        self.allowed_count.save(deps.storage, &count)
     }
 
     pub fn remove_addrs(&self, deps: &mut DepsMut, removed_addrs: Vec<Addr>) -> StdResult<()> {
+        let mut count = self.count(&deps.as_ref())?;
         for addr in removed_addrs {
-            self.allowed_addrs.remove(deps.storage, addr);
+            if self.allowed_addrs.has(deps.storage, addr.clone()) {
+                count = count.checked_sub(1).ok_or_else(|| {
+                    StdError::overflow(OverflowError::new(OverflowOperation::Sub, count, 1))
+                })?;
+            }
+            self.allowed_addrs.remove(deps.storage, addr.clone());
+            self.quotas.remove(deps.storage, addr.clone());
+            self.quota_used.remove(deps.storage, addr.clone());
+            self.windows.remove(deps.storage, addr);
         }
-        Ok(())
+        self.allowed_count.save(deps.storage, &count)
     }
 
     pub fn clear_addrs(&self, deps: &mut DepsMut) -> StdResult<()> {
         self.allowed_addrs.clear(deps.storage);
-        Ok(())
+        self.quotas.clear(deps.storage);
+        self.quota_used.clear(deps.storage);
+        self.windows.clear(deps.storage);
+        self.allowed_count.save(deps.storage, &0)
+    }
+
+    // count returns the number of allowed addresses without walking the list,
+    // but for lists stored before the count was kept, which get it saved by
+    // their next update
+    pub fn count(&self, deps: &Deps) -> StdResult<u64> {
+        match self.allowed_count.may_load(deps.storage)? {
+            Some(count) => Ok(count),
+            None => Ok(self
+                .allowed_addrs
+                .keys_raw(deps.storage, None, None, Order::Ascending)
+                .count() as u64),
+        }
+    }
+
+    pub fn allowed_entries(
+        &self,
+        deps: &Deps,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<AllowedEntry>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+
+        self.allowed_addrs
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|address| {
+                let address = address?;
+                Ok(AllowedEntry {
+                    quota: self.quotas.may_load(deps.storage, address.clone())?,
+                    window: self.windows.may_load(deps.storage, address.clone())?,
+                    address,
+                })
+            })
+            .collect()
     }
 
     pub fn is_allowed_addr(&self, deps: &Deps, addr: Addr) -> StdResult<bool> {
         Ok(self.allowed_addrs.has(deps.storage, addr))
     }
 
-    pub fn is_allowed(&self, deps: &Deps, addr: Addr) -> StdResult<bool> {
+    pub fn is_allowed(&self, deps: &Deps, env: &Env, addr: Addr) -> StdResult<bool> {
+        self.is_allowed_at(deps, addr, env.block.time)
+    }
+
+    // is_allowed_at checks the address against the list and the validity
+    // windows at the given time, or only against the denied addresses in
+    // denylist mode
+    pub fn is_allowed_at(&self, deps: &Deps, addr: Addr, time: Timestamp) -> StdResult<bool> {
         if !(self.enabled.load(deps.storage)?) {
             Ok(true)
+        } else if self.get_mode(deps)? == Mode::Denylist {
+            Ok(!self.denied_addrs.has(deps.storage, addr))
+        } else if !self.in_default_window(deps, time)? {
+            Ok(false)
         } else {
-            Ok(self.allowed_addrs.has(deps.storage, addr))
+            Ok(self.allowed_addrs.has(deps.storage, addr.clone())
+                && self.in_window(deps, addr, time)?)
         }
     }
 }
@@ -99,9 +198,10 @@ impl<'a> Module for Allowable<'a> {
         info: MessageInfo,
         msg: Self::ExecuteMsg,
     ) -> Result<Response, Self::Error> {
-        let owner_module = self.ownable.borrow();
-        let loaded_owner = owner_module.get_owner(&deps.as_ref()).unwrap();
-        if info.sender != loaded_owner {
+        let roles = self.roles.borrow();
+        if self.ownable.borrow().is_renounced(&deps.as_ref())? {
+            Err(AllowableError::OwnershipRenounced {})
+        } else if !roles.is_authorized(&deps.as_ref(), Role::AllowlistManager, &info.sender)? {
             Err(AllowableError::Unauthorized {})
         } else {
             match msg {
@@ -117,7 +217,21 @@ impl<'a> Module for Allowable<'a> {
                     Ok(resp)
                 }
 
-                ExecuteMsg::AddAllowedAddrs { addresses } => {
+                ExecuteMsg::AddAllowedAddrs {
+                    addresses,
+                    quota,
+                    window,
+                } => {
+                    // re-adding an address keeps its quota and window unless
+                    // new ones are given
+                    for addr in &addresses {
+                        if quota.is_some() {
+                            self.set_quota(deps, addr.clone(), quota)?;
+                        }
+                        if window.is_some() {
+                            self.set_window(deps, addr.clone(), window.clone())?;
+                        }
+                    }
                     self.allow_addrs(deps, addresses)?;
                     let resp = Response::new();
                     Ok(resp)
@@ -128,6 +242,42 @@ impl<'a> Module for Allowable<'a> {
                     let resp = Response::new();
                     Ok(resp)
                 }
+
+                ExecuteMsg::SetMode { mode } => {
+                    self.set_mode(deps, mode)?;
+                    let resp = Response::new();
+                    Ok(resp)
+                }
+
+                ExecuteMsg::AddDeniedAddrs { addresses } => {
+                    self.deny_addrs(deps, addresses)?;
+                    let resp = Response::new();
+                    Ok(resp)
+                }
+
+                ExecuteMsg::RemoveDeniedAddrs { addresses } => {
+                    self.remove_denied_addrs(deps, addresses)?;
+                    let resp = Response::new();
+                    Ok(resp)
+                }
+
+                ExecuteMsg::SetDefaultWindow { window } => {
+                    self.set_default_window(deps, window)?;
+                    let resp = Response::new();
+                    Ok(resp)
+                }
+
+                ExecuteMsg::SetQuota { address, quota } => {
+                    self.set_quota(deps, address, quota)?;
+                    let resp = Response::new();
+                    Ok(resp)
+                }
+
+                ExecuteMsg::SetMerkleRoot { root } => {
+                    self.set_merkle_root(deps, root)?;
+                    let resp = Response::new();
+                    Ok(resp)
+                }
             }
         }
     }
@@ -135,12 +285,13 @@ impl<'a> Module for Allowable<'a> {
     fn query(
         &self,
         deps: &Deps,
-        _: Env,
+        env: Env,
         msg: Self::QueryMsg,
     ) -> Result<Self::QueryResp, Self::Error> {
         match msg {
-            QueryMsg::IsAllowed { address } => {
-                let is_allowed = self.is_allowed(deps, address)?;
+            QueryMsg::IsAllowed { address, time } => {
+                let time = time.unwrap_or(env.block.time);
+                let is_allowed = self.is_allowed_at(deps, address, time)?;
                 let resp = QueryResp::IsAllowed { is_allowed };
                 Ok(resp)
             }
@@ -149,6 +300,59 @@ impl<'a> Module for Allowable<'a> {
                 let resp = QueryResp::IsAllowedAddr(is_allowed);
                 Ok(resp)
             }
+            QueryMsg::IsAllowedWithProof {
+                address,
+                data,
+                proof,
+            } => {
+                let is_allowed = self.is_allowed_with_proof(deps, &env, address, data, proof)?;
+                let resp = QueryResp::IsAllowed { is_allowed };
+                Ok(resp)
+            }
+            QueryMsg::RemainingQuota {
+                address,
+                data,
+                proof,
+            } => {
+                let remaining =
+                    if self.is_allowed_with_proof(deps, &env, address.clone(), data, proof)? {
+                        self.remaining_quota(deps, address)?
+                    } else {
+                        Some(0)
+                    };
+                let resp = QueryResp::RemainingQuota { remaining };
+                Ok(resp)
+            }
+            QueryMsg::IsDeniedAddr(address) => {
+                let is_denied = self.is_denied_addr(deps, address)?;
+                let resp = QueryResp::IsDeniedAddr(is_denied);
+                Ok(resp)
+            }
+            QueryMsg::Mode {} => {
+                let mode = self.get_mode(deps)?;
+                let resp = QueryResp::Mode { mode };
+                Ok(resp)
+            }
+            QueryMsg::DefaultWindow {} => {
+                let window = self.default_window(deps)?;
+                let resp = QueryResp::DefaultWindow { window };
+                Ok(resp)
+            }
+            QueryMsg::MerkleRoot {} => {
+                let root = self.merkle_root(deps)?;
+                let resp = QueryResp::MerkleRoot { root };
+                Ok(resp)
+            }
+            QueryMsg::AllowedAddrs { start_after, limit } => {
+                let entries = self.allowed_entries(deps, start_after, limit)?;
+                let resp = QueryResp::AllowedAddrs { entries };
+                Ok(resp)
+            }
+            QueryMsg::Count {} => {
+                let count = self.count(deps)?;
+                let resp = QueryResp::Count { count };
+                Ok(resp)
+            }
             QueryMsg::IsEnabled {} => {
                 let is_enabled = self.get_enabled(deps)?;
                 let resp = QueryResp::IsEnabled { is_enabled };
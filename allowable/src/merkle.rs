@@ -0,0 +1,94 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, StdResult};
+use sha2::{Digest, Sha256};
+
+use crate::denylist::Mode;
+use crate::errors::AllowableError;
+use crate::Allowable;
+
+type Hash = [u8; 32];
+
+// leaf hashes an allowlist entry: sha256("<address>"), or
+// sha256("<address>:<data>") for entries carrying per-address data
+pub fn leaf(address: &Addr, data: Option<&str>) -> Hash {
+    let entry = match data {
+        Some(data) => format!("{}:{}", address, data),
+        None => address.to_string(),
+    };
+    Sha256::digest(entry.as_bytes()).into()
+}
+
+// verify walks the proof up from the leaf, hashing each pair in sorted order
+// so the proof doesn't need to say which side every sibling is on
+pub fn verify(root: &Hash, leaf: Hash, proof: &[Hash]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        let mut hasher = Sha256::new();
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().into()
+    });
+    computed == *root
+}
+
+fn decode_hash(hex_hash: &str) -> Option<Hash> {
+    hex::decode(hex_hash).ok()?.try_into().ok()
+}
+
+impl<'a> Allowable<'a> {
+    pub fn merkle_root(&self, deps: &Deps) -> StdResult<Option<String>> {
+        self.merkle_root.may_load(deps.storage)
+    }
+
+    // set_merkle_root switches the allowlist to proofs against the given hex
+    // encoded root, None goes back to the stored addresses only
+    pub fn set_merkle_root(
+        &self,
+        deps: &mut DepsMut,
+        root: Option<String>,
+    ) -> Result<(), AllowableError> {
+        match root {
+            Some(root) => {
+                if decode_hash(&root).is_none() {
+                    return Err(AllowableError::InvalidMerkleRoot { root });
+                }
+                self.merkle_root.save(deps.storage, &root.to_lowercase())?;
+            }
+            None => self.merkle_root.remove(deps.storage),
+        }
+        Ok(())
+    }
+
+    // is_allowed_with_proof accepts addresses stored in the allowlist as well
+    // as those proving their entry is under the merkle root, within the
+    // default window
+    pub fn is_allowed_with_proof(
+        &self,
+        deps: &Deps,
+        env: &Env,
+        addr: Addr,
+        data: Option<String>,
+        proof: Vec<String>,
+    ) -> Result<bool, AllowableError> {
+        let allowed = self.is_allowed(deps, env, addr.clone())?;
+        // proofs only add to an allowlist, they can't lift a denial
+        if allowed || self.get_mode(deps)? == Mode::Denylist {
+            return Ok(allowed);
+        }
+        if !self.in_default_window(deps, env.block.time)? {
+            return Ok(false);
+        }
+        let root = match self.merkle_root(deps)? {
+            Some(root) => decode_hash(&root).ok_or(AllowableError::InvalidMerkleRoot { root })?,
+            None => return Ok(false),
+        };
+        let proof = proof
+            .into_iter()
+            .map(|node| decode_hash(&node).ok_or(AllowableError::InvalidMerkleProof { node }))
+            .collect::<Result<Vec<Hash>, AllowableError>>()?;
+        Ok(verify(&root, leaf(&addr, data.as_deref()), &proof))
+    }
+}
@@ -1,7 +1,10 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Timestamp};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::denylist::Mode;
+use crate::windows::Window;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct InstantiateMsg {
@@ -12,23 +15,106 @@ pub struct InstantiateMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-    AddAllowedAddrs { addresses: Vec<Addr> },
-    RemoveAllowedAddrs { addresses: Vec<Addr> },
+    // AddAllowedAddrs allows the addresses to buy up to quota items each, any
+    // number if quota is unset, and only within window if set
+    AddAllowedAddrs {
+        addresses: Vec<Addr>,
+        #[serde(default)]
+        quota: Option<u32>,
+        #[serde(default)]
+        window: Option<Window>,
+    },
+    RemoveAllowedAddrs {
+        addresses: Vec<Addr>,
+    },
     ClearAllAllowedAddrs {},
-    SetEnabled { enabled: bool },
+    SetEnabled {
+        enabled: bool,
+    },
+    // SetMode switches between allowing only the allowed addresses and
+    // allowing everyone but the denied addresses
+    SetMode {
+        mode: Mode,
+    },
+    AddDeniedAddrs {
+        addresses: Vec<Addr>,
+    },
+    RemoveDeniedAddrs {
+        addresses: Vec<Addr>,
+    },
+    // SetDefaultWindow bounds every entry of the list, unset for no bounds
+    SetDefaultWindow {
+        window: Option<Window>,
+    },
+    // SetQuota limits the items the address may buy without storing it in
+    // the list, e.g. for addresses proving their entry against the merkle
+    // root, unset for no limit
+    SetQuota {
+        address: Addr,
+        quota: Option<u32>,
+    },
+    // SetMerkleRoot sets the hex encoded root addresses can prove their entry
+    // against, unset to only allow the stored addresses
+    SetMerkleRoot {
+        root: Option<String>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    // IsAllowed returns whether or not the address is allowed or the allowlist is disabled
-    IsAllowed { address: Addr },
+    // IsAllowed returns whether or not the address is allowed or the allowlist is disabled,
+    // at the given time or the current block time
+    IsAllowed {
+        address: Addr,
+        #[serde(default)]
+        time: Option<Timestamp>,
+    },
     // IsAllowedAddr returns whether or not the address is in the allowlist
     IsAllowedAddr(Addr),
+    // IsAllowedWithProof is IsAllowed for addresses proving their entry, with its
+    // optional data, against the merkle root using hex encoded proof nodes
+    IsAllowedWithProof {
+        address: Addr,
+        data: Option<String>,
+        proof: Vec<String>,
+    },
+    // RemainingQuota returns how many more items the address may buy, zero
+    // if it isn't allowed. Addresses admitted by the merkle root pass their
+    // entry's data and proof as for IsAllowedWithProof
+    RemainingQuota {
+        address: Addr,
+        data: Option<String>,
+        #[serde(default)]
+        proof: Vec<String>,
+    },
+    // IsDeniedAddr returns whether or not the address is in the denylist
+    IsDeniedAddr(Addr),
+    // Mode returns whether the list is an allowlist or a denylist
+    Mode {},
+    // DefaultWindow returns the window bounding every entry, if any
+    DefaultWindow {},
+    // MerkleRoot returns the hex encoded merkle root, if any
+    MerkleRoot {},
+    // AllowedAddrs lists the allowed addresses with their quota and window
+    AllowedAddrs {
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
+    // Count returns the number of allowed addresses
+    Count {},
     // IsEnabled returns whether or not the contract is enforcing allowability check
     IsEnabled {},
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AllowedEntry {
+    pub address: Addr,
+    pub quota: Option<u32>,
+    pub window: Option<Window>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryResp {
@@ -38,4 +124,16 @@ pub enum QueryResp {
     IsAllowedAddr(bool),
     // IsEnabled returns true if the contract is using an allowlist
     IsEnabled { is_enabled: bool },
+    AllowedAddrs { entries: Vec<AllowedEntry> },
+    Count { count: u64 },
+    // RemainingQuota is None if the address may buy any number of items
+    RemainingQuota { remaining: Option<u32> },
+    // IsDeniedAddr returns true if the address exists in the denylist
+    IsDeniedAddr(bool),
+    // Mode returns whether the list is an allowlist or a denylist
+    Mode { mode: Mode },
+    // DefaultWindow returns the window bounding every entry, if any
+    DefaultWindow { window: Option<Window> },
+    // MerkleRoot returns the hex encoded merkle root, if any
+    MerkleRoot { root: Option<String> },
 }
@@ -0,0 +1,62 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, StdResult};
+
+use crate::errors::AllowableError;
+use crate::Allowable;
+
+impl<'a> Allowable<'a> {
+    // set_quota limits how many items the address may buy, None removes the
+    // limit
+    pub fn set_quota(&self, deps: &mut DepsMut, addr: Addr, quota: Option<u32>) -> StdResult<()> {
+        match quota {
+            Some(quota) => self.quotas.save(deps.storage, addr, &quota),
+            None => {
+                self.quotas.remove(deps.storage, addr);
+                Ok(())
+            }
+        }
+    }
+
+    // remaining_quota returns how many more items the address may buy, None
+    // meaning no limit, the caller checks the address is allowed at all
+    pub fn remaining_quota(&self, deps: &Deps, addr: Addr) -> StdResult<Option<u32>> {
+        if !self.get_enabled(deps)? {
+            return Ok(None);
+        }
+        let quota = match self.quotas.may_load(deps.storage, addr.clone())? {
+            Some(quota) => quota,
+            None => return Ok(None),
+        };
+        let used = self.quota_used.may_load(deps.storage, addr)?.unwrap_or(0);
+        Ok(Some(quota.saturating_sub(used)))
+    }
+
+    // consume_quota takes `amount` items off the address' quota, either all
+    // of them or, erroring, none. Addresses admitted by the merkle root pass
+    // their entry's data and proof, stored ones an empty proof
+    pub fn consume_quota(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        addr: Addr,
+        data: Option<String>,
+        proof: Vec<String>,
+        amount: u32,
+    ) -> Result<(), AllowableError> {
+        if !self.is_allowed_with_proof(&deps.as_ref(), env, addr.clone(), data, proof)? {
+            return Err(AllowableError::NotAllowed {});
+        }
+        let remaining = match self.remaining_quota(&deps.as_ref(), addr.clone())? {
+            Some(remaining) => remaining,
+            None => return Ok(()),
+        };
+        if amount > remaining {
+            return Err(AllowableError::QuotaExceeded { remaining });
+        }
+        let used = self
+            .quota_used
+            .may_load(deps.storage, addr.clone())?
+            .unwrap_or(0);
+        self.quota_used.save(deps.storage, addr, &(used + amount))?;
+        Ok(())
+    }
+}
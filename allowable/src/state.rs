@@ -1,5 +1,16 @@
 use cosmwasm_std::Addr;
 use cw_storage_plus::{Item, Map};
 
+use crate::denylist::Mode;
+use crate::windows::Window;
+
 pub const ALLOWED_ADDRS: Map<Addr, bool> = Map::new("allowed_addrs");
+pub const ALLOWED_COUNT: Item<u64> = Item::new("allowed_count");
 pub const ENABLED: Item<bool> = Item::new("enabled");
+pub const MERKLE_ROOT: Item<String> = Item::new("merkle_root");
+pub const QUOTAS: Map<Addr, u32> = Map::new("allowed_quotas");
+pub const QUOTA_USED: Map<Addr, u32> = Map::new("allowed_quota_used");
+pub const WINDOWS: Map<Addr, Window> = Map::new("allowed_windows");
+pub const DEFAULT_WINDOW: Item<Window> = Item::new("allowed_default_window");
+pub const MODE: Item<Mode> = Item::new("allowlist_mode");
+pub const DENIED_ADDRS: Map<Addr, bool> = Map::new("denied_addrs");
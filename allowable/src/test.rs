@@ -3,13 +3,30 @@ mod tests {
     use burnt_glue::module::Module;
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr, DepsMut, Env, MessageInfo,
+        Addr, Deps, DepsMut, Env, MessageInfo, StdError, Timestamp,
     };
 
-    use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, QueryResp};
+    use crate::denylist::Mode;
+    use crate::errors::AllowableError;
+    use crate::msg::{AllowedEntry, ExecuteMsg, InstantiateMsg, QueryMsg, QueryResp};
+    use crate::windows::Window;
     use crate::Allowable;
 
     const CREATOR: &str = "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z";
+    const BUYER: &str = "burnt1e2fuwe3uhq8zd9nkkk876nawrwdulgv47mkgww";
+    const USER: &str = "burnt188rjfzzrdxlus60zgnrvs4rg0l73hct3mlvdpe";
+
+    // MERKLE_ROOT is the root of the leaves BUYER, USER, "CREATOR:vip" and
+    // cosmos1rcvrcvrcvrcvrcvrcvrcvrcvrcvrcvrcvrcvrc
+    const MERKLE_ROOT: &str = "e3cc42d78ef8dc59c06d036dc7a348aee58e1cf7d8888ed82284ce4521b4dc89";
+    const BUYER_PROOF: [&str; 2] = [
+        "eb44c29695911873fdaa42fb80414a3d1aba98b6f543da132a0010247a2ee94e",
+        "2d05f3e8d4a74572457df1be8e484c88d613517ac2a129462f6c6ed125acde31",
+    ];
+    const CREATOR_PROOF: [&str; 2] = [
+        "7b86ca88703e334f77dfaa395463248cfc190516da05f0f33a2463ed0e5664c5",
+        "18eb57d67c04473d5a719599f7fd3630a589a8e0239978e9728abd3751a29a9d",
+    ];
 
     fn setup_allowable(deps: &mut DepsMut, _env: &Env, info: &MessageInfo) -> Allowable<'static> {
         let mut allowable = Allowable::default();
@@ -84,6 +101,7 @@ mod tests {
 
         let msg = QueryMsg::IsAllowed {
             address: addrs[0].clone(),
+            time: None,
         };
         let info = mock_info(CREATOR, &[]);
         let allowed = allowable.query(&deps.as_ref(), env.clone(), msg).unwrap();
@@ -92,6 +110,8 @@ mod tests {
         let info = mock_info(CREATOR, &[]);
         let msg = ExecuteMsg::AddAllowedAddrs {
             addresses: addrs.clone(),
+            quota: None,
+            window: None,
         };
         allowable
             .execute(&mut deps.as_mut(), env.clone(), info, msg)
@@ -99,6 +119,7 @@ mod tests {
 
         let msg = QueryMsg::IsAllowed {
             address: addrs[0].clone(),
+            time: None,
         };
         let info = mock_info(CREATOR, &[]);
         let allowed = allowable.query(&deps.as_ref(), env.clone(), msg).unwrap();
@@ -106,6 +127,7 @@ mod tests {
 
         let msg = QueryMsg::IsAllowed {
             address: addrs[1].clone(),
+            time: None,
         };
         let info = mock_info(CREATOR, &[]);
         let allowed = allowable.query(&deps.as_ref(), env.clone(), msg).unwrap();
@@ -119,21 +141,21 @@ mod tests {
             .unwrap();
 
         let allowed = allowable
-            .is_allowed(&deps.as_ref(), addrs[0].clone())
+            .is_allowed(&deps.as_ref(), &env, addrs[0].clone())
             .unwrap();
         assert!(!allowed);
 
         // Clear all addresses
         allowable.clear_addrs(&mut deps.as_mut()).unwrap();
         let allowed = allowable
-            .is_allowed(&deps.as_ref(), addrs[1].clone())
+            .is_allowed(&deps.as_ref(), &env, addrs[1].clone())
             .unwrap();
         assert!(!allowed);
 
         // If disabled, everyone is allowed.
         allowable.set_enabled(&mut deps.as_mut(), false).unwrap();
         let allowed = allowable
-            .is_allowed(&deps.as_ref(), addrs[0].clone())
+            .is_allowed(&deps.as_ref(), &env, addrs[0].clone())
             .unwrap();
         assert!(allowed);
     }
@@ -169,6 +191,8 @@ mod tests {
 
         let msg = ExecuteMsg::AddAllowedAddrs {
             addresses: Vec::new(),
+            quota: None,
+            window: None,
         };
 
         // Execute a message that is not allowed
@@ -184,10 +208,700 @@ mod tests {
                 env,
                 QueryMsg::IsAllowed {
                     address: addrs[0].clone(),
+                    time: None,
                 },
             )
             .unwrap();
 
         assert_eq!(result, QueryResp::IsAllowed { is_allowed: false });
     }
+
+    #[test]
+    fn merkle_root() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut allowable = setup_allowable(&mut deps.as_mut(), &env, &info);
+        allowable
+            .instantiate(
+                &mut deps.as_mut(),
+                &env,
+                &info,
+                InstantiateMsg {
+                    enabled: true,
+                    allowed_addrs: vec![],
+                },
+            )
+            .unwrap();
+
+        let err = allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetMerkleRoot {
+                    root: Some("not a root".to_string()),
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(err, AllowableError::InvalidMerkleRoot { .. }));
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetMerkleRoot {
+                    root: Some(MERKLE_ROOT.to_string()),
+                },
+            )
+            .unwrap();
+
+        let is_allowed = |allowable: &Allowable,
+                          deps: Deps,
+                          address: &str,
+                          data: Option<&str>,
+                          proof: &[&str]| {
+            allowable
+                .query(
+                    &deps,
+                    mock_env(),
+                    QueryMsg::IsAllowedWithProof {
+                        address: Addr::unchecked(address),
+                        data: data.map(str::to_string),
+                        proof: proof.iter().map(|node| node.to_string()).collect(),
+                    },
+                )
+                .unwrap()
+        };
+        let allowed = QueryResp::IsAllowed { is_allowed: true };
+        let denied = QueryResp::IsAllowed { is_allowed: false };
+        assert_eq!(
+            is_allowed(&allowable, deps.as_ref(), BUYER, None, &BUYER_PROOF),
+            allowed
+        );
+        assert_eq!(
+            is_allowed(
+                &allowable,
+                deps.as_ref(),
+                CREATOR,
+                Some("vip"),
+                &CREATOR_PROOF
+            ),
+            allowed
+        );
+        // the data is part of the leaf
+        assert_eq!(
+            is_allowed(&allowable, deps.as_ref(), CREATOR, None, &CREATOR_PROOF),
+            denied
+        );
+        assert_eq!(
+            is_allowed(
+                &allowable,
+                deps.as_ref(),
+                CREATOR,
+                Some("vvip"),
+                &CREATOR_PROOF
+            ),
+            denied
+        );
+        // a proof only works for its own leaf
+        assert_eq!(
+            is_allowed(&allowable, deps.as_ref(), USER, None, &BUYER_PROOF),
+            denied
+        );
+        assert_eq!(
+            is_allowed(&allowable, deps.as_ref(), BUYER, None, &BUYER_PROOF[..1]),
+            denied
+        );
+
+        // unsetting the root drops the proofs
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                info,
+                ExecuteMsg::SetMerkleRoot { root: None },
+            )
+            .unwrap();
+        assert_eq!(
+            is_allowed(&allowable, deps.as_ref(), BUYER, None, &BUYER_PROOF),
+            denied
+        );
+    }
+
+    #[test]
+    fn quotas() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut allowable = setup_allowable(&mut deps.as_mut(), &env, &info);
+        allowable
+            .instantiate(
+                &mut deps.as_mut(),
+                &env,
+                &info,
+                InstantiateMsg {
+                    enabled: true,
+                    allowed_addrs: vec![Addr::unchecked(USER)],
+                },
+            )
+            .unwrap();
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info,
+                ExecuteMsg::AddAllowedAddrs {
+                    addresses: vec![Addr::unchecked(BUYER)],
+                    quota: Some(3),
+                    window: None,
+                },
+            )
+            .unwrap();
+
+        let remaining = |deps: Deps, address: &str| {
+            allowable
+                .query(
+                    &deps,
+                    mock_env(),
+                    QueryMsg::RemainingQuota {
+                        address: Addr::unchecked(address),
+                        data: None,
+                        proof: vec![],
+                    },
+                )
+                .unwrap()
+        };
+        assert_eq!(
+            remaining(deps.as_ref(), BUYER),
+            QueryResp::RemainingQuota { remaining: Some(3) }
+        );
+        assert_eq!(
+            remaining(deps.as_ref(), USER),
+            QueryResp::RemainingQuota { remaining: None }
+        );
+        assert_eq!(
+            remaining(deps.as_ref(), CREATOR),
+            QueryResp::RemainingQuota { remaining: Some(0) }
+        );
+
+        let buyer = Addr::unchecked(BUYER);
+        allowable
+            .consume_quota(&mut deps.as_mut(), &env, buyer.clone(), None, vec![], 2)
+            .unwrap();
+        // asking for more than remains consumes nothing
+        let err = allowable
+            .consume_quota(&mut deps.as_mut(), &env, buyer.clone(), None, vec![], 2)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AllowableError::QuotaExceeded { remaining: 1 }
+        ));
+        allowable
+            .consume_quota(&mut deps.as_mut(), &env, buyer.clone(), None, vec![], 1)
+            .unwrap();
+        assert_eq!(
+            remaining(deps.as_ref(), BUYER),
+            QueryResp::RemainingQuota { remaining: Some(0) }
+        );
+        let err = allowable
+            .consume_quota(&mut deps.as_mut(), &env, buyer, None, vec![], 1)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AllowableError::QuotaExceeded { remaining: 0 }
+        ));
+
+        allowable
+            .consume_quota(
+                &mut deps.as_mut(),
+                &env,
+                Addr::unchecked(USER),
+                None,
+                vec![],
+                100,
+            )
+            .unwrap();
+        let err = allowable
+            .consume_quota(
+                &mut deps.as_mut(),
+                &env,
+                Addr::unchecked(CREATOR),
+                None,
+                vec![],
+                1,
+            )
+            .unwrap_err();
+        assert!(matches!(err, AllowableError::NotAllowed {}));
+    }
+
+    #[test]
+    fn quota_updates() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut allowable = setup_allowable(&mut deps.as_mut(), &env, &info);
+        allowable
+            .instantiate(
+                &mut deps.as_mut(),
+                &env,
+                &info,
+                InstantiateMsg {
+                    enabled: true,
+                    allowed_addrs: vec![],
+                },
+            )
+            .unwrap();
+        let add = |allowable: &mut Allowable, mut deps: DepsMut, quota: Option<u32>| {
+            allowable
+                .execute(
+                    &mut deps,
+                    mock_env(),
+                    mock_info(CREATOR, &[]),
+                    ExecuteMsg::AddAllowedAddrs {
+                        addresses: vec![Addr::unchecked(BUYER)],
+                        quota,
+                        window: None,
+                    },
+                )
+                .unwrap();
+        };
+        let remaining = |allowable: &Allowable, deps: Deps| {
+            allowable
+                .remaining_quota(&deps, Addr::unchecked(BUYER))
+                .unwrap()
+        };
+
+        add(&mut allowable, deps.as_mut(), Some(3));
+        allowable
+            .consume_quota(
+                &mut deps.as_mut(),
+                &env,
+                Addr::unchecked(BUYER),
+                None,
+                vec![],
+                2,
+            )
+            .unwrap();
+        // re-adding without a quota keeps the existing one and its usage
+        add(&mut allowable, deps.as_mut(), None);
+        assert_eq!(remaining(&allowable, deps.as_ref()), Some(1));
+
+        // removing the address resets its usage
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::RemoveAllowedAddrs {
+                    addresses: vec![Addr::unchecked(BUYER)],
+                },
+            )
+            .unwrap();
+        add(&mut allowable, deps.as_mut(), Some(3));
+        assert_eq!(remaining(&allowable, deps.as_ref()), Some(3));
+
+        // and so does clearing the list
+        allowable
+            .consume_quota(
+                &mut deps.as_mut(),
+                &env,
+                Addr::unchecked(BUYER),
+                None,
+                vec![],
+                3,
+            )
+            .unwrap();
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::ClearAllAllowedAddrs {},
+            )
+            .unwrap();
+        add(&mut allowable, deps.as_mut(), Some(3));
+        assert_eq!(remaining(&allowable, deps.as_ref()), Some(3));
+
+        // addresses admitted by the merkle root consume their quota with
+        // their proof
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetMerkleRoot {
+                    root: Some(MERKLE_ROOT.to_string()),
+                },
+            )
+            .unwrap();
+        let creator = Addr::unchecked(CREATOR);
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info,
+                ExecuteMsg::SetQuota {
+                    address: creator.clone(),
+                    quota: Some(1),
+                },
+            )
+            .unwrap();
+        // setting a quota doesn't store the address in the list
+        assert!(!allowable
+            .is_allowed_addr(&deps.as_ref(), creator.clone())
+            .unwrap());
+        let proof = || CREATOR_PROOF.iter().map(|node| node.to_string()).collect();
+        let err = allowable
+            .consume_quota(&mut deps.as_mut(), &env, creator.clone(), None, vec![], 1)
+            .unwrap_err();
+        assert!(matches!(err, AllowableError::NotAllowed {}));
+        assert_eq!(
+            allowable
+                .query(
+                    &deps.as_ref(),
+                    env.clone(),
+                    QueryMsg::RemainingQuota {
+                        address: creator.clone(),
+                        data: Some("vip".to_string()),
+                        proof: proof(),
+                    },
+                )
+                .unwrap(),
+            QueryResp::RemainingQuota { remaining: Some(1) }
+        );
+        allowable
+            .consume_quota(
+                &mut deps.as_mut(),
+                &env,
+                creator.clone(),
+                Some("vip".to_string()),
+                proof(),
+                1,
+            )
+            .unwrap();
+        let err = allowable
+            .consume_quota(
+                &mut deps.as_mut(),
+                &env,
+                creator,
+                Some("vip".to_string()),
+                proof(),
+                1,
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AllowableError::QuotaExceeded { remaining: 0 }
+        ));
+    }
+
+    #[test]
+    fn validity_windows() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut allowable = setup_allowable(&mut deps.as_mut(), &env, &info);
+        allowable
+            .instantiate(
+                &mut deps.as_mut(),
+                &env,
+                &info,
+                InstantiateMsg {
+                    enabled: true,
+                    allowed_addrs: vec![Addr::unchecked(USER)],
+                },
+            )
+            .unwrap();
+
+        // BUYER gets early access for an hour before the list opens
+        let now = env.block.time;
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::AddAllowedAddrs {
+                    addresses: vec![Addr::unchecked(BUYER)],
+                    quota: None,
+                    window: Some(Window {
+                        start: Some(now),
+                        end: Some(now.plus_seconds(3600)),
+                    }),
+                },
+            )
+            .unwrap();
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetDefaultWindow {
+                    window: Some(Window {
+                        start: None,
+                        end: Some(now.plus_seconds(7200)),
+                    }),
+                },
+            )
+            .unwrap();
+
+        // windows closing before they open are rejected
+        let err = allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::AddAllowedAddrs {
+                    addresses: vec![Addr::unchecked(BUYER)],
+                    quota: None,
+                    window: Some(Window {
+                        start: Some(now),
+                        end: Some(now),
+                    }),
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(err, AllowableError::InvalidWindow { .. }));
+        let err = allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetDefaultWindow {
+                    window: Some(Window {
+                        start: Some(now.plus_seconds(7200)),
+                        end: Some(now),
+                    }),
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(err, AllowableError::InvalidWindow { .. }));
+
+        // re-adding BUYER without a window keeps the early access one
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info,
+                ExecuteMsg::AddAllowedAddrs {
+                    addresses: vec![Addr::unchecked(BUYER)],
+                    quota: None,
+                    window: None,
+                },
+            )
+            .unwrap();
+
+        let is_allowed = |deps: Deps, env: &Env, address: &str, time: Option<Timestamp>| {
+            allowable
+                .query(
+                    &deps,
+                    env.clone(),
+                    QueryMsg::IsAllowed {
+                        address: Addr::unchecked(address),
+                        time,
+                    },
+                )
+                .unwrap()
+                == QueryResp::IsAllowed { is_allowed: true }
+        };
+        assert!(is_allowed(deps.as_ref(), &env, BUYER, None));
+        assert!(is_allowed(deps.as_ref(), &env, USER, None));
+        assert!(!is_allowed(
+            deps.as_ref(),
+            &env,
+            BUYER,
+            Some(now.minus_seconds(1))
+        ));
+
+        // the end of the entry's window is excluded
+        env.block.time = now.plus_seconds(3600);
+        assert!(!is_allowed(deps.as_ref(), &env, BUYER, None));
+        assert!(is_allowed(deps.as_ref(), &env, USER, None));
+        assert!(!allowable
+            .is_allowed(&deps.as_ref(), &env, Addr::unchecked(BUYER))
+            .unwrap());
+
+        // nobody is allowed past the default window
+        env.block.time = now.plus_seconds(7200);
+        assert!(!is_allowed(deps.as_ref(), &env, USER, None));
+    }
+
+    #[test]
+    fn denylist_mode() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut allowable = setup_allowable(&mut deps.as_mut(), &env, &info);
+        allowable
+            .instantiate(
+                &mut deps.as_mut(),
+                &env,
+                &info,
+                InstantiateMsg {
+                    enabled: true,
+                    allowed_addrs: vec![Addr::unchecked(USER)],
+                },
+            )
+            .unwrap();
+        let mode = allowable
+            .query(&deps.as_ref(), env.clone(), QueryMsg::Mode {})
+            .unwrap();
+        assert_eq!(
+            mode,
+            QueryResp::Mode {
+                mode: Mode::Allowlist
+            }
+        );
+
+        for msg in [
+            ExecuteMsg::SetMode {
+                mode: Mode::Denylist,
+            },
+            ExecuteMsg::AddDeniedAddrs {
+                addresses: vec![Addr::unchecked(USER), Addr::unchecked(BUYER)],
+            },
+            ExecuteMsg::RemoveDeniedAddrs {
+                addresses: vec![Addr::unchecked(BUYER)],
+            },
+        ] {
+            allowable
+                .execute(&mut deps.as_mut(), env.clone(), info.clone(), msg)
+                .unwrap();
+        }
+
+        // the allowed addresses don't matter in denylist mode
+        let is_allowed = |deps: Deps, address: &str| {
+            allowable
+                .is_allowed(&deps, &mock_env(), Addr::unchecked(address))
+                .unwrap()
+        };
+        assert!(!is_allowed(deps.as_ref(), USER));
+        assert!(is_allowed(deps.as_ref(), BUYER));
+        assert!(is_allowed(deps.as_ref(), CREATOR));
+        let denied = allowable
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::IsDeniedAddr(Addr::unchecked(USER)),
+            )
+            .unwrap();
+        assert_eq!(denied, QueryResp::IsDeniedAddr(true));
+
+        // back to the allowlist
+        allowable
+            .set_mode(&mut deps.as_mut(), Mode::Allowlist)
+            .unwrap();
+        assert!(is_allowed(deps.as_ref(), USER));
+        assert!(!is_allowed(deps.as_ref(), BUYER));
+    }
+
+    #[test]
+    fn list_and_count() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut allowable = setup_allowable(&mut deps.as_mut(), &env, &info);
+        allowable
+            .instantiate(
+                &mut deps.as_mut(),
+                &env,
+                &info,
+                InstantiateMsg {
+                    enabled: true,
+                    allowed_addrs: vec![Addr::unchecked(USER), Addr::unchecked(CREATOR)],
+                },
+            )
+            .unwrap();
+        // adding an address twice counts it once
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::AddAllowedAddrs {
+                    addresses: vec![Addr::unchecked(BUYER), Addr::unchecked(USER)],
+                    quota: Some(2),
+                    window: None,
+                },
+            )
+            .unwrap();
+        let count = allowable
+            .query(&deps.as_ref(), env.clone(), QueryMsg::Count {})
+            .unwrap();
+        assert_eq!(count, QueryResp::Count { count: 3 });
+
+        let page = allowable
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::AllowedAddrs {
+                    start_after: Some(Addr::unchecked(USER)),
+                    limit: Some(1),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            page,
+            QueryResp::AllowedAddrs {
+                entries: vec![AllowedEntry {
+                    address: Addr::unchecked(BUYER),
+                    quota: Some(2),
+                    window: None,
+                }]
+            }
+        );
+
+        // removing an address that isn't listed doesn't change the count
+        allowable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info,
+                ExecuteMsg::RemoveAllowedAddrs {
+                    addresses: vec![Addr::unchecked(USER), Addr::unchecked("unknown")],
+                },
+            )
+            .unwrap();
+        assert_eq!(allowable.count(&deps.as_ref()).unwrap(), 2);
+        let entries = allowable
+            .allowed_entries(&deps.as_ref(), None, None)
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.address)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![Addr::unchecked(BUYER), Addr::unchecked(CREATOR)]
+        );
+
+        // lists stored before the count was kept are counted entry by entry
+        allowable.allowed_count.remove(deps.as_mut().storage);
+        assert_eq!(allowable.count(&deps.as_ref()).unwrap(), 2);
+        allowable
+            .remove_addrs(&mut deps.as_mut(), vec![Addr::unchecked(BUYER)])
+            .unwrap();
+        assert_eq!(
+            allowable.allowed_count.load(deps.as_ref().storage).unwrap(),
+            1
+        );
+
+        // a count out of step with the list errors rather than wrapping
+        allowable
+            .allowed_count
+            .save(deps.as_mut().storage, &0)
+            .unwrap();
+        let err = allowable
+            .remove_addrs(&mut deps.as_mut(), vec![Addr::unchecked(CREATOR)])
+            .unwrap_err();
+        assert!(matches!(err, StdError::Overflow { .. }));
+
+        allowable.clear_addrs(&mut deps.as_mut()).unwrap();
+        assert_eq!(allowable.count(&deps.as_ref()).unwrap(), 0);
+    }
 }
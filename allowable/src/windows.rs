@@ -0,0 +1,86 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, StdResult, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::AllowableError;
+use crate::Allowable;
+
+// Window is the time an allowlist entry is valid, from start included to end
+// excluded, either bound being open if unset
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Window {
+    pub start: Option<Timestamp>,
+    pub end: Option<Timestamp>,
+}
+
+impl Window {
+    // check rejects windows that close before they open, they would never
+    // contain any time
+    pub fn check(&self) -> Result<(), AllowableError> {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) if start >= end => {
+                Err(AllowableError::InvalidWindow { start, end })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn contains(&self, time: Timestamp) -> bool {
+        self.start.is_none_or(|start| time >= start) && self.end.is_none_or(|end| time < end)
+    }
+}
+
+impl<'a> Allowable<'a> {
+    pub fn default_window(&self, deps: &Deps) -> StdResult<Option<Window>> {
+        self.default_window.may_load(deps.storage)
+    }
+
+    // set_default_window bounds every entry of the list, None removes the
+    // bounds
+    pub fn set_default_window(
+        &self,
+        deps: &mut DepsMut,
+        window: Option<Window>,
+    ) -> Result<(), AllowableError> {
+        match window {
+            Some(window) => {
+                window.check()?;
+                self.default_window.save(deps.storage, &window)?;
+            }
+            None => self.default_window.remove(deps.storage),
+        }
+        Ok(())
+    }
+
+    // set_window bounds the address' entry on top of the default window, None
+    // removes the bounds
+    pub fn set_window(
+        &self,
+        deps: &mut DepsMut,
+        addr: Addr,
+        window: Option<Window>,
+    ) -> Result<(), AllowableError> {
+        match window {
+            Some(window) => {
+                window.check()?;
+                self.windows.save(deps.storage, addr, &window)?;
+            }
+            None => self.windows.remove(deps.storage, addr),
+        }
+        Ok(())
+    }
+
+    pub(crate) fn in_default_window(&self, deps: &Deps, time: Timestamp) -> StdResult<bool> {
+        Ok(self
+            .default_window(deps)?
+            .is_none_or(|window| window.contains(time)))
+    }
+
+    pub(crate) fn in_window(&self, deps: &Deps, addr: Addr, time: Timestamp) -> StdResult<bool> {
+        Ok(self
+            .windows
+            .may_load(deps.storage, addr)?
+            .is_none_or(|window| window.contains(time)))
+    }
+}
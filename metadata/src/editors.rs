@@ -0,0 +1,165 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Order, StdResult};
+use cw_storage_plus::Bound;
+use ownable::roles::Role;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{to_json_value, Metadata, MetadataError, DEFAULT_LIMIT, MAX_LIMIT};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Editor {
+    pub address: Addr,
+    // fields limits the editor to these top level fields, all fields if unset
+    pub fields: Option<Vec<String>>,
+}
+
+impl<'a, T> Metadata<'a, T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    pub fn add_editor(
+        &self,
+        deps: &mut DepsMut,
+        address: &Addr,
+        fields: Option<Vec<String>>,
+    ) -> StdResult<()> {
+        let address = deps.api.addr_validate(address.as_str())?;
+        self.editors.save(
+            deps.storage,
+            &address,
+            &Editor {
+                address: address.clone(),
+                fields,
+            },
+        )
+    }
+
+    pub fn remove_editor(&self, deps: &mut DepsMut, address: &Addr) -> StdResult<()> {
+        self.editors.remove(deps.storage, address);
+        Ok(())
+    }
+
+    pub fn editors(
+        &self,
+        deps: &Deps,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<Editor>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.as_ref().map(Bound::exclusive);
+
+        self.editors
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(_, editor)| editor))
+            .collect()
+    }
+
+    // check_editor returns the fields the sender may edit, None meaning every
+    // field, or an error if the sender is neither the owner, a metadata
+    // editor role holder nor an editor
+    pub(crate) fn check_editor(
+        &self,
+        deps: &Deps,
+        sender: &Addr,
+    ) -> Result<Option<Vec<String>>, MetadataError> {
+        if self.ownable.borrow().is_renounced(deps)? {
+            return Err(MetadataError::OwnershipRenounced {});
+        }
+        let roles = self.roles.borrow();
+        if roles.is_authorized(deps, Role::MetadataEditor, sender)? {
+            return Ok(None);
+        }
+        // editors wait for co-owner approval or the timelock like role holders
+        if roles.is_suspended(deps)? {
+            return Err(MetadataError::Unauthorized {});
+        }
+        match self.editors.may_load(deps.storage, sender)? {
+            Some(editor) => Ok(editor.fields),
+            None => Err(MetadataError::Unauthorized {}),
+        }
+    }
+
+    // check_set_allowed makes sure a restricted editor only changes the
+    // fields they were given
+    pub(crate) fn check_set_allowed(
+        &self,
+        deps: &Deps,
+        fields: &Option<Vec<String>>,
+        meta: &T,
+    ) -> Result<(), MetadataError> {
+        let fields = match fields {
+            Some(fields) => fields,
+            None => return Ok(()),
+        };
+        let current = to_json_value(&self.metadata.load(deps.storage)?)?;
+        let new = to_json_value(meta)?;
+        check_fields(fields, changed_fields(&current, &new))
+    }
+
+    // check_token_allowed makes sure a restricted editor only touches the
+    // token overrides of the fields they were given
+    pub(crate) fn check_token_allowed(
+        &self,
+        deps: &Deps,
+        fields: &Option<Vec<String>>,
+        token_id: &str,
+        overrides: Option<&Value>,
+    ) -> Result<(), MetadataError> {
+        let fields = match fields {
+            Some(fields) => fields,
+            None => return Ok(()),
+        };
+        let mut touched = top_level_fields(overrides)?;
+        let current = self.get_token_overrides(deps, token_id)?;
+        touched.extend(top_level_fields(current.as_ref())?);
+        check_fields(fields, touched)
+    }
+}
+
+// check_patch_allowed makes sure a restricted editor only patches the fields
+// they were given
+pub(crate) fn check_patch_allowed(
+    fields: &Option<Vec<String>>,
+    patch: &Value,
+) -> Result<(), MetadataError> {
+    match fields {
+        Some(fields) => check_fields(fields, top_level_fields(Some(patch))?),
+        None => Ok(()),
+    }
+}
+
+fn check_fields(allowed: &[String], touched: Vec<String>) -> Result<(), MetadataError> {
+    match touched.into_iter().find(|field| !allowed.contains(field)) {
+        Some(field) => Err(MetadataError::FieldNotEditable { field }),
+        None => Ok(()),
+    }
+}
+
+fn changed_fields(old: &Value, new: &Value) -> Vec<String> {
+    let mut keys: Vec<String> = vec![];
+    for doc in [old, new] {
+        if let Value::Object(map) = doc {
+            keys.extend(map.keys().cloned());
+        }
+    }
+    keys.sort();
+    keys.dedup();
+    keys.retain(|key| old.get(key) != new.get(key));
+    keys
+}
+
+// top_level_fields lists the keys of a JSON object; anything other than an
+// object replaces the whole document and so touches every field
+fn top_level_fields(doc: Option<&Value>) -> Result<Vec<String>, MetadataError> {
+    match doc {
+        None => Ok(vec![]),
+        Some(Value::Object(map)) => Ok(map.keys().cloned().collect()),
+        Some(_) => Err(MetadataError::FieldNotEditable {
+            field: "*".to_string(),
+        }),
+    }
+}
@@ -1,9 +1,15 @@
+pub mod editors;
+mod test;
+mod token_metadata;
+pub mod validation;
+
 use cosmwasm_std::StdError;
-use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, StdResult};
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Order, StdResult, Timestamp};
+use cw_storage_plus::{Bound, Item, Map};
 use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::cell::RefCell;
 use std::fmt::Debug;
 use std::rc::Rc;
@@ -11,22 +17,78 @@ use thiserror::Error;
 
 use burnt_glue::module::Module;
 use burnt_glue::response::Response;
+use editors::{check_patch_allowed, Editor};
+use ownable::roles::{Roles, ROLE_MEMBERS};
 use ownable::Ownable;
+use validation::{MetadataValidator, ValidationError};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
 
 pub struct Metadata<'a, T>
 where
     T: Serialize + DeserializeOwned,
 {
     pub metadata: Item<'a, T>,
+    pub history: Map<'a, u32, MetadataRevision<T>>,
+    pub version: Item<'a, u32>,
+    pub frozen: Item<'a, bool>,
+    pub frozen_fields: Map<'a, &'a str, bool>,
+    pub token_metadata: Map<'a, &'a str, Value>,
+    pub editors: Map<'a, &'a Addr, Editor>,
+    pub roles: Rc<RefCell<Roles<'a>>>,
     ownable: Rc<RefCell<Ownable<'a>>>,
+    validator: Option<Box<dyn MetadataValidator<T> + 'a>>,
+}
+
+// MetadataKeys are the storage keys of a Metadata module, a contract
+// composing more than one gives each its own keys
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MetadataKeys<'a> {
+    pub metadata: &'a str,
+    pub history: &'a str,
+    pub version: &'a str,
+    pub frozen: &'a str,
+    pub frozen_fields: &'a str,
+    pub token_metadata: &'a str,
+    pub editors: &'a str,
+}
+
+impl Default for MetadataKeys<'static> {
+    fn default() -> Self {
+        Self {
+            metadata: "metadata",
+            history: "metadata_history",
+            version: "metadata_version",
+            frozen: "metadata_frozen",
+            frozen_fields: "metadata_frozen_fields",
+            token_metadata: "token_metadata",
+            editors: "metadata_editors",
+        }
+    }
 }
 
 impl<'a, T> Metadata<'a, T>
 where
     T: Serialize + DeserializeOwned,
 {
-    pub fn new(metadata: Item<'a, T>, ownable: Rc<RefCell<Ownable<'a>>>) -> Self {
-        Self { metadata, ownable }
+    pub fn new(
+        keys: MetadataKeys<'a>,
+        ownable: Rc<RefCell<Ownable<'a>>>,
+        validator: Option<Box<dyn MetadataValidator<T> + 'a>>,
+    ) -> Self {
+        Self {
+            metadata: Item::new(keys.metadata),
+            history: Map::new(keys.history),
+            version: Item::new(keys.version),
+            frozen: Item::new(keys.frozen),
+            frozen_fields: Map::new(keys.frozen_fields),
+            token_metadata: Map::new(keys.token_metadata),
+            editors: Map::new(keys.editors),
+            roles: Rc::new(RefCell::new(Roles::new(ROLE_MEMBERS, ownable.clone()))),
+            ownable,
+            validator,
+        }
     }
 }
 
@@ -35,13 +97,23 @@ where
     T: Serialize + DeserializeOwned,
 {
     fn default() -> Self {
-        Self {
-            metadata: Item::new("metadata"),
-            ownable: Rc::new(RefCell::new(Ownable::default())),
-        }
+        Self::new(
+            MetadataKeys::default(),
+            Rc::new(RefCell::new(Ownable::default())),
+            None,
+        )
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MetadataRevision<T> {
+    pub version: u32,
+    pub metadata: T,
+    pub author: Addr,
+    pub time: Timestamp,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct InstantiateMsg<T> {
@@ -52,18 +124,91 @@ pub struct InstantiateMsg<T> {
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg<T> {
     SetMetadata(T),
+    // RollbackMetadata restores the metadata of a previous version as a new
+    // revision, owner only
+    RollbackMetadata {
+        version: u32,
+    },
+    // PatchMetadata applies an RFC 7396 JSON merge patch to the stored
+    // metadata, owner only
+    PatchMetadata(Value),
+    // FreezeMetadata permanently locks the given top level fields, or the
+    // whole metadata if none are given, owner only
+    FreezeMetadata {
+        fields: Option<Vec<String>>,
+    },
+    // SetTokenMetadata replaces the overrides of a single token
+    SetTokenMetadata {
+        token_id: String,
+        metadata: Value,
+    },
+    // UpdateTokenMetadata merge patches the overrides of a single token
+    UpdateTokenMetadata {
+        token_id: String,
+        patch: Value,
+    },
+    // DeleteTokenMetadata drops the overrides of a single token
+    DeleteTokenMetadata {
+        token_id: String,
+    },
+    // AddEditor lets the address edit the metadata, optionally limited to
+    // the given top level fields, owner only
+    AddEditor {
+        address: Addr,
+        fields: Option<Vec<String>>,
+    },
+    // RemoveEditor revokes an editor, owner only
+    RemoveEditor {
+        address: Addr,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     GetMetadata {},
+    // GetMetadataVersion returns a single revision of the metadata
+    GetMetadataVersion {
+        version: u32,
+    },
+    // MetadataHistory returns revisions of the metadata by version
+    MetadataHistory {
+        start_after: Option<u32>,
+        limit: Option<u32>,
+    },
+    // IsFrozen returns whether the metadata, or a single field of it, is frozen
+    IsFrozen {
+        field: Option<String>,
+    },
+    // FrozenFields returns the individually frozen fields
+    FrozenFields {},
+    // GetTokenMetadata returns the collection metadata merged with the
+    // overrides of the token
+    GetTokenMetadata {
+        token_id: String,
+    },
+    // GetTokenOverrides returns only the overrides of the token
+    GetTokenOverrides {
+        token_id: String,
+    },
+    // Editors returns the delegated metadata editors
+    Editors {
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryResp<T> {
     Metadata(T),
+    Revision(MetadataRevision<T>),
+    History(Vec<MetadataRevision<T>>),
+    IsFrozen(bool),
+    FrozenFields(Vec<String>),
+    TokenMetadata(Value),
+    TokenOverrides(Option<Value>),
+    Editors(Vec<Editor>),
 }
 
 #[derive(Error, Debug)]
@@ -74,6 +219,30 @@ pub enum MetadataError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Ownership renounced, metadata is immutable")]
+    OwnershipRenounced {},
+
+    #[error("Metadata version {version} not found")]
+    VersionNotFound { version: u32 },
+
+    #[error("Patched metadata is invalid: {error}")]
+    InvalidPatch { error: String },
+
+    #[error("Metadata is invalid: {}", errors.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(", "))]
+    ValidationFailed { errors: Vec<ValidationError> },
+
+    #[error("Metadata is frozen")]
+    Frozen {},
+
+    #[error("Metadata field {field} is frozen")]
+    FieldFrozen { field: String },
+
+    #[error("Token metadata must be a JSON object")]
+    InvalidTokenMetadata {},
+
+    #[error("Metadata field {field} is not editable by sender")]
+    FieldNotEditable { field: String },
+
     #[error("Custom Error val: {val:?}")]
     CustomError { val: String },
     // Add any other custom errors you like here.
@@ -88,8 +257,185 @@ where
         self.metadata.load(deps.storage)
     }
 
-    pub fn set_metadata(&self, deps: &mut DepsMut, meta: &T) -> StdResult<()> {
-        self.metadata.save(deps.storage, meta)
+    pub fn validate(&self, meta: &T) -> Result<(), MetadataError> {
+        match &self.validator {
+            Some(validator) => validator
+                .validate(meta)
+                .map_err(|errors| MetadataError::ValidationFailed { errors }),
+            None => Ok(()),
+        }
+    }
+
+    // set_metadata validates the metadata, stores it as a new revision and
+    // returns its version
+    pub fn set_metadata(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        author: &Addr,
+        meta: T,
+    ) -> Result<u32, MetadataError> {
+        self.check_frozen(&deps.as_ref(), &meta)?;
+        self.validate(&meta)?;
+        let version = self.version.may_load(deps.storage)?.unwrap_or(0) + 1;
+        let revision = MetadataRevision {
+            version,
+            metadata: meta,
+            author: author.clone(),
+            time: env.block.time,
+        };
+        self.metadata.save(deps.storage, &revision.metadata)?;
+        self.history.save(deps.storage, version, &revision)?;
+        self.version.save(deps.storage, &version)?;
+        Ok(version)
+    }
+
+    pub fn get_revision(
+        &self,
+        deps: &Deps,
+        version: u32,
+    ) -> Result<MetadataRevision<T>, MetadataError> {
+        self.history
+            .may_load(deps.storage, version)?
+            .ok_or(MetadataError::VersionNotFound { version })
+    }
+
+    pub fn metadata_history(
+        &self,
+        deps: &Deps,
+        start_after: Option<u32>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<MetadataRevision<T>>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+
+        self.history
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(_, revision)| revision))
+            .collect()
+    }
+
+    // rollback_metadata appends the metadata of an earlier version as a new
+    // revision, so the history itself is never rewritten
+    pub fn rollback_metadata(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        author: &Addr,
+        version: u32,
+    ) -> Result<u32, MetadataError> {
+        let revision = self.get_revision(&deps.as_ref(), version)?;
+        self.set_metadata(deps, env, author, revision.metadata)
+    }
+
+    // patch_metadata merges the patch into the current metadata and stores the
+    // result as a new revision, provided it still deserializes into T
+    pub fn patch_metadata(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        author: &Addr,
+        patch: &Value,
+    ) -> Result<u32, MetadataError> {
+        let current = self.metadata.load(deps.storage)?;
+        let mut doc = to_json_value(&current)?;
+        merge_patch(&mut doc, patch);
+        let patched: T = serde_json::from_value(doc).map_err(|e| MetadataError::InvalidPatch {
+            error: e.to_string(),
+        })?;
+        self.set_metadata(deps, env, author, patched)
+    }
+
+    pub fn is_frozen(&self, deps: &Deps) -> StdResult<bool> {
+        Ok(self.frozen.may_load(deps.storage)?.unwrap_or(false))
+    }
+
+    pub fn is_field_frozen(&self, deps: &Deps, field: &str) -> StdResult<bool> {
+        Ok(self.is_frozen(deps)? || self.frozen_fields.has(deps.storage, field))
+    }
+
+    pub fn frozen_fields(&self, deps: &Deps) -> StdResult<Vec<String>> {
+        self.frozen_fields
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect()
+    }
+
+    // freeze locks the given fields, or everything if no fields are given;
+    // there is no way to unfreeze
+    pub fn freeze(&self, deps: &mut DepsMut, fields: Option<Vec<String>>) -> StdResult<()> {
+        match fields {
+            Some(fields) => {
+                for field in fields {
+                    self.frozen_fields
+                        .save(deps.storage, field.as_str(), &true)?;
+                }
+                Ok(())
+            }
+            None => self.frozen.save(deps.storage, &true),
+        }
+    }
+
+    // check_frozen fails if the metadata is frozen or if the new metadata
+    // changes any frozen field
+    fn check_frozen(&self, deps: &Deps, meta: &T) -> Result<(), MetadataError> {
+        if self.is_frozen(deps)? {
+            return Err(MetadataError::Frozen {});
+        }
+        let fields = self.frozen_fields(deps)?;
+        if fields.is_empty() {
+            return Ok(());
+        }
+        let current = match self.metadata.may_load(deps.storage)? {
+            Some(current) => to_json_value(&current)?,
+            None => return Ok(()),
+        };
+        let new = to_json_value(meta)?;
+        for field in fields {
+            if current.get(&field) != new.get(&field) {
+                return Err(MetadataError::FieldFrozen { field });
+            }
+        }
+        Ok(())
+    }
+
+    fn check_owner(&self, deps: &Deps, sender: &Addr) -> Result<(), MetadataError> {
+        let owner_module = self.ownable.borrow();
+        if owner_module.is_renounced(deps)? {
+            Err(MetadataError::OwnershipRenounced {})
+        } else if !owner_module.is_owner(deps, sender)? {
+            Err(MetadataError::Unauthorized {})
+        } else {
+            Ok(())
+        }
+    }
+}
+
+pub(crate) fn to_json_value<T: Serialize>(meta: &T) -> StdResult<Value> {
+    serde_json::to_value(meta)
+        .map_err(|e| StdError::serialize_err(std::any::type_name::<T>(), e.to_string()))
+}
+
+// merge_patch applies a JSON merge patch as described in RFC 7396
+pub(crate) fn merge_patch(target: &mut Value, patch: &Value) {
+    let patch = match patch {
+        Value::Object(patch) => patch,
+        _ => {
+            *target = patch.clone();
+            return;
+        }
+    };
+    if !target.is_object() {
+        *target = Value::Object(Default::default());
+    }
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.as_str()).or_insert(Value::Null), value);
+            }
+        }
     }
 }
 
@@ -106,11 +452,11 @@ where
     fn instantiate(
         &mut self,
         deps: &mut DepsMut,
-        _: &Env,
-        _: &MessageInfo,
+        env: &Env,
+        info: &MessageInfo,
         msg: Self::InstantiateMsg,
     ) -> Result<Response, Self::Error> {
-        self.metadata.save(deps.storage, &msg.metadata)?;
+        self.set_metadata(deps, env, &info.sender, msg.metadata)?;
 
         Ok(Response::new())
     }
@@ -118,21 +464,85 @@ where
     fn execute(
         &mut self,
         deps: &mut DepsMut,
-        _: Env,
+        env: Env,
         info: MessageInfo,
         msg: Self::ExecuteMsg,
     ) -> Result<Response, Self::Error> {
         match msg {
             ExecuteMsg::SetMetadata(meta) => {
-                let owner_module = self.ownable.borrow();
-                let loaded_owner = owner_module.get_owner(&deps.as_ref()).unwrap();
-                if info.sender != loaded_owner {
-                    Err(MetadataError::Unauthorized {})
-                } else {
-                    self.metadata.save(deps.storage, &meta).unwrap();
-                    let resp = Response::new();
-                    Ok(resp)
+                let fields = self.check_editor(&deps.as_ref(), &info.sender)?;
+                self.check_set_allowed(&deps.as_ref(), &fields, &meta)?;
+                let version = self.set_metadata(deps, &env, &info.sender, meta)?;
+                Ok(Response::new()
+                    .add_attribute("action", "set_metadata")
+                    .add_attribute("version", version.to_string()))
+            }
+            ExecuteMsg::RollbackMetadata { version } => {
+                self.check_owner(&deps.as_ref(), &info.sender)?;
+                let new_version = self.rollback_metadata(deps, &env, &info.sender, version)?;
+                Ok(Response::new()
+                    .add_attribute("action", "rollback_metadata")
+                    .add_attribute("rollback_to", version.to_string())
+                    .add_attribute("version", new_version.to_string()))
+            }
+            ExecuteMsg::PatchMetadata(patch) => {
+                let fields = self.check_editor(&deps.as_ref(), &info.sender)?;
+                check_patch_allowed(&fields, &patch)?;
+                let version = self.patch_metadata(deps, &env, &info.sender, &patch)?;
+                Ok(Response::new()
+                    .add_attribute("action", "patch_metadata")
+                    .add_attribute("version", version.to_string()))
+            }
+            ExecuteMsg::FreezeMetadata { fields } => {
+                self.check_owner(&deps.as_ref(), &info.sender)?;
+                if self.is_frozen(&deps.as_ref())? {
+                    return Err(MetadataError::Frozen {});
                 }
+                let resp = Response::new().add_attribute("action", "freeze_metadata");
+                let resp = match &fields {
+                    Some(fields) => resp.add_attribute("fields", fields.join(",")),
+                    None => resp,
+                };
+                self.freeze(deps, fields)?;
+                Ok(resp)
+            }
+            ExecuteMsg::SetTokenMetadata { token_id, metadata } => {
+                let fields = self.check_editor(&deps.as_ref(), &info.sender)?;
+                self.check_token_allowed(&deps.as_ref(), &fields, &token_id, Some(&metadata))?;
+                self.set_token_metadata(deps, &token_id, metadata)?;
+                Ok(Response::new()
+                    .add_attribute("action", "set_token_metadata")
+                    .add_attribute("token_id", token_id))
+            }
+            ExecuteMsg::UpdateTokenMetadata { token_id, patch } => {
+                let fields = self.check_editor(&deps.as_ref(), &info.sender)?;
+                check_patch_allowed(&fields, &patch)?;
+                self.update_token_metadata(deps, &token_id, &patch)?;
+                Ok(Response::new()
+                    .add_attribute("action", "update_token_metadata")
+                    .add_attribute("token_id", token_id))
+            }
+            ExecuteMsg::DeleteTokenMetadata { token_id } => {
+                let fields = self.check_editor(&deps.as_ref(), &info.sender)?;
+                self.check_token_allowed(&deps.as_ref(), &fields, &token_id, None)?;
+                self.delete_token_metadata(deps, &token_id)?;
+                Ok(Response::new()
+                    .add_attribute("action", "delete_token_metadata")
+                    .add_attribute("token_id", token_id))
+            }
+            ExecuteMsg::AddEditor { address, fields } => {
+                self.check_owner(&deps.as_ref(), &info.sender)?;
+                self.add_editor(deps, &address, fields)?;
+                Ok(Response::new()
+                    .add_attribute("action", "add_editor")
+                    .add_attribute("address", address))
+            }
+            ExecuteMsg::RemoveEditor { address } => {
+                self.check_owner(&deps.as_ref(), &info.sender)?;
+                self.remove_editor(deps, &address)?;
+                Ok(Response::new()
+                    .add_attribute("action", "remove_editor")
+                    .add_attribute("address", address))
             }
         }
     }
@@ -149,6 +559,37 @@ where
                 let resp = QueryResp::Metadata(loaded_metadata);
                 Ok(resp)
             }
+            QueryMsg::GetMetadataVersion { version } => {
+                let revision = self.get_revision(deps, version)?;
+                Ok(QueryResp::Revision(revision))
+            }
+            QueryMsg::MetadataHistory { start_after, limit } => {
+                let history = self.metadata_history(deps, start_after, limit)?;
+                Ok(QueryResp::History(history))
+            }
+            QueryMsg::IsFrozen { field } => {
+                let frozen = match field {
+                    Some(field) => self.is_field_frozen(deps, &field)?,
+                    None => self.is_frozen(deps)?,
+                };
+                Ok(QueryResp::IsFrozen(frozen))
+            }
+            QueryMsg::FrozenFields {} => {
+                let fields = self.frozen_fields(deps)?;
+                Ok(QueryResp::FrozenFields(fields))
+            }
+            QueryMsg::GetTokenMetadata { token_id } => {
+                let metadata = self.get_token_metadata(deps, &token_id)?;
+                Ok(QueryResp::TokenMetadata(metadata))
+            }
+            QueryMsg::GetTokenOverrides { token_id } => {
+                let overrides = self.get_token_overrides(deps, &token_id)?;
+                Ok(QueryResp::TokenOverrides(overrides))
+            }
+            QueryMsg::Editors { start_after, limit } => {
+                let editors = self.editors(deps, start_after, limit)?;
+                Ok(QueryResp::Editors(editors))
+            }
         }
     }
 }
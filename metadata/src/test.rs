@@ -0,0 +1,845 @@
+#[cfg(test)]
+mod tests {
+    use burnt_glue::module::Module;
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, DepsMut,
+    };
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::validation::{MaxSize, ValidationError};
+    use crate::{
+        ExecuteMsg, InstantiateMsg, Metadata, MetadataError, MetadataKeys, QueryMsg, QueryResp,
+    };
+    use ownable::roles::{Role, Roles};
+    use ownable::timelock::{self, Timelock};
+    use ownable::Ownable;
+
+    const CREATOR: &str = "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z";
+    const USER: &str = "burnt188rjfzzrdxlus60zgnrvs4rg0l73hct3mlvdpe";
+    const EDITOR: &str = "burnt1e2fuwe3uhq8zd9nkkk876nawrwdulgv4cxkq74";
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    struct Collection {
+        name: String,
+        description: Option<String>,
+    }
+
+    fn collection(name: &str) -> Collection {
+        Collection {
+            name: name.to_string(),
+            description: None,
+        }
+    }
+
+    fn setup<'a>(deps: &mut DepsMut) -> Metadata<'a, Collection> {
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let ownable = Rc::new(RefCell::new(Ownable::default()));
+        ownable
+            .borrow_mut()
+            .instantiate(deps, &env, &info, ownable::InstantiateMsg::default())
+            .unwrap();
+        let mut metadata = Metadata::new(MetadataKeys::default(), ownable, None);
+        metadata
+            .instantiate(
+                deps,
+                &env,
+                &info,
+                InstantiateMsg {
+                    metadata: collection("first"),
+                },
+            )
+            .unwrap();
+        metadata
+    }
+
+    #[test]
+    fn history_and_rollback() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut metadata = setup(&mut deps.as_mut());
+
+        let mut later = env.clone();
+        later.block.time = env.block.time.plus_seconds(60);
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                later.clone(),
+                info.clone(),
+                ExecuteMsg::SetMetadata(collection("second")),
+            )
+            .unwrap();
+
+        let revision = metadata
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::GetMetadataVersion { version: 2 },
+            )
+            .unwrap();
+        match revision {
+            QueryResp::Revision(revision) => {
+                assert_eq!(revision.metadata, collection("second"));
+                assert_eq!(revision.author, Addr::unchecked(CREATOR));
+                assert_eq!(revision.time, later.block.time);
+            }
+            _ => panic!(),
+        }
+
+        // only the owner can roll back
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                later.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::RollbackMetadata { version: 1 },
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::Unauthorized {}));
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                later.clone(),
+                info.clone(),
+                ExecuteMsg::RollbackMetadata { version: 7 },
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::VersionNotFound { version: 7 }));
+
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                later.clone(),
+                info.clone(),
+                ExecuteMsg::RollbackMetadata { version: 1 },
+            )
+            .unwrap();
+        let current = metadata
+            .query(&deps.as_ref(), env.clone(), QueryMsg::GetMetadata {})
+            .unwrap();
+        assert_eq!(current, QueryResp::Metadata(collection("first")));
+
+        // a rollback is appended to the history rather than rewriting it
+        let history = metadata
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::MetadataHistory {
+                    start_after: Some(1),
+                    limit: Some(10),
+                },
+            )
+            .unwrap();
+        match history {
+            QueryResp::History(history) => {
+                let versions: Vec<u32> = history.iter().map(|r| r.version).collect();
+                assert_eq!(versions, vec![2, 3]);
+                assert_eq!(history[1].metadata, collection("first"));
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn merge_patch() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut metadata = setup(&mut deps.as_mut());
+
+        // fields missing from the patch are left untouched
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::PatchMetadata(json!({ "description": "a collection" })),
+            )
+            .unwrap();
+        let current = metadata
+            .query(&deps.as_ref(), env.clone(), QueryMsg::GetMetadata {})
+            .unwrap();
+        assert_eq!(
+            current,
+            QueryResp::Metadata(Collection {
+                name: "first".to_string(),
+                description: Some("a collection".to_string()),
+            })
+        );
+
+        // null removes a field
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::PatchMetadata(json!({ "name": "second", "description": null })),
+            )
+            .unwrap();
+        let current = metadata
+            .query(&deps.as_ref(), env.clone(), QueryMsg::GetMetadata {})
+            .unwrap();
+        assert_eq!(current, QueryResp::Metadata(collection("second")));
+
+        // the patched document has to deserialize into the metadata type
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::PatchMetadata(json!({ "name": null })),
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::InvalidPatch { .. }));
+
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::PatchMetadata(json!({ "name": "third" })),
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::Unauthorized {}));
+    }
+
+    #[test]
+    fn validated_metadata() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let ownable = Rc::new(RefCell::new(Ownable::default()));
+        ownable
+            .borrow_mut()
+            .instantiate(
+                &mut deps.as_mut(),
+                &env,
+                &info,
+                ownable::InstantiateMsg::default(),
+            )
+            .unwrap();
+        let non_empty_name = |meta: &Collection| {
+            if meta.name.is_empty() {
+                return Err(vec![ValidationError::new("name", "must not be empty")]);
+            }
+            Ok(())
+        };
+        let mut metadata = Metadata::new(
+            MetadataKeys::default(),
+            ownable.clone(),
+            Some(Box::new(non_empty_name)),
+        );
+
+        // validation runs on instantiate
+        let err = metadata
+            .instantiate(
+                &mut deps.as_mut(),
+                &env,
+                &info,
+                InstantiateMsg {
+                    metadata: collection(""),
+                },
+            )
+            .unwrap_err();
+        match err {
+            MetadataError::ValidationFailed { errors } => {
+                assert_eq!(
+                    errors,
+                    vec![ValidationError::new("name", "must not be empty")]
+                )
+            }
+            _ => panic!(),
+        }
+        metadata
+            .instantiate(
+                &mut deps.as_mut(),
+                &env,
+                &info,
+                InstantiateMsg {
+                    metadata: collection("first"),
+                },
+            )
+            .unwrap();
+
+        // and on updates
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetMetadata(collection("")),
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::ValidationFailed { .. }));
+        let current = metadata
+            .query(&deps.as_ref(), env.clone(), QueryMsg::GetMetadata {})
+            .unwrap();
+        assert_eq!(current, QueryResp::Metadata(collection("first")));
+
+        let mut metadata: Metadata<Collection> = Metadata::new(
+            MetadataKeys::default(),
+            ownable,
+            Some(Box::new(MaxSize { max_bytes: 40 })),
+        );
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetMetadata(collection("short")),
+            )
+            .unwrap();
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                info,
+                ExecuteMsg::SetMetadata(collection("a name well over the size limit")),
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::ValidationFailed { .. }));
+    }
+
+    #[test]
+    fn frozen_metadata() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+        let mut metadata = setup(&mut deps.as_mut());
+
+        // freeze the name only
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::FreezeMetadata {
+                    fields: Some(vec!["name".to_string()]),
+                },
+            )
+            .unwrap();
+        let frozen = metadata
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::IsFrozen {
+                    field: Some("name".to_string()),
+                },
+            )
+            .unwrap();
+        assert_eq!(frozen, QueryResp::IsFrozen(true));
+        let frozen = metadata
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::IsFrozen { field: None },
+            )
+            .unwrap();
+        assert_eq!(frozen, QueryResp::IsFrozen(false));
+
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::PatchMetadata(json!({ "name": "second" })),
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::FieldFrozen { field } if field == "name"));
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetMetadata(Collection {
+                    name: "first".to_string(),
+                    description: Some("still editable".to_string()),
+                }),
+            )
+            .unwrap();
+
+        // freezing everything locks the owner out for good
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::FreezeMetadata { fields: None },
+            )
+            .unwrap();
+        let frozen = metadata
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::IsFrozen { field: None },
+            )
+            .unwrap();
+        assert_eq!(frozen, QueryResp::IsFrozen(true));
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetMetadata(collection("first")),
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::Frozen {}));
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                info,
+                ExecuteMsg::RollbackMetadata { version: 1 },
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::Frozen {}));
+    }
+
+    #[test]
+    fn token_overrides() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+        let mut metadata = setup(&mut deps.as_mut());
+
+        let msg = ExecuteMsg::SetTokenMetadata {
+            token_id: "1".to_string(),
+            metadata: json!({ "seat": 12, "section": "A" }),
+        };
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                msg.clone(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::Unauthorized {}));
+        metadata
+            .execute(&mut deps.as_mut(), env.clone(), info.clone(), msg)
+            .unwrap();
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::UpdateTokenMetadata {
+                    token_id: "1".to_string(),
+                    patch: json!({ "section": null, "gate": "north", "description": "VIP" }),
+                },
+            )
+            .unwrap();
+
+        let token = metadata
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::GetTokenMetadata {
+                    token_id: "1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            token,
+            QueryResp::TokenMetadata(json!({
+                "name": "first",
+                "description": "VIP",
+                "seat": 12,
+                "gate": "north",
+            }))
+        );
+
+        // tokens without overrides get the collection metadata
+        let token = metadata
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::GetTokenMetadata {
+                    token_id: "2".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            token,
+            QueryResp::TokenMetadata(json!({ "name": "first", "description": null }))
+        );
+
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::DeleteTokenMetadata {
+                    token_id: "1".to_string(),
+                },
+            )
+            .unwrap();
+        let overrides = metadata
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::GetTokenOverrides {
+                    token_id: "1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(overrides, QueryResp::TokenOverrides(None));
+
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetTokenMetadata {
+                    token_id: "3".to_string(),
+                    metadata: json!({ "name": "third" }),
+                },
+            )
+            .unwrap();
+
+        // overrides cannot shadow frozen fields
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::FreezeMetadata {
+                    fields: Some(vec!["name".to_string()]),
+                },
+            )
+            .unwrap();
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetTokenMetadata {
+                    token_id: "1".to_string(),
+                    metadata: json!({ "name": "renamed" }),
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::FieldFrozen { .. }));
+
+        // nor can existing overrides of frozen fields be dropped
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::DeleteTokenMetadata {
+                    token_id: "3".to_string(),
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::FieldFrozen { field } if field == "name"));
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::UpdateTokenMetadata {
+                    token_id: "3".to_string(),
+                    patch: json!({ "name": null }),
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::FieldFrozen { .. }));
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                info,
+                ExecuteMsg::UpdateTokenMetadata {
+                    token_id: "3".to_string(),
+                    patch: json!({ "seat": 1 }),
+                },
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn delegated_editors() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+        let mut metadata = setup(&mut deps.as_mut());
+
+        // metadata editor role holders edit every field
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(EDITOR, &[]),
+                ExecuteMsg::SetMetadata(collection("by role")),
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::Unauthorized {}));
+        Roles::default()
+            .grant_role(
+                &mut deps.as_mut(),
+                Role::MetadataEditor,
+                &Addr::unchecked(EDITOR),
+            )
+            .unwrap();
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(EDITOR, &[]),
+                ExecuteMsg::SetMetadata(collection("by role")),
+            )
+            .unwrap();
+
+        // editors are managed by the owner only
+        let msg = ExecuteMsg::AddEditor {
+            address: Addr::unchecked(USER),
+            fields: Some(vec!["description".to_string(), "seat".to_string()]),
+        };
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                msg.clone(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::Unauthorized {}));
+        metadata
+            .execute(&mut deps.as_mut(), env.clone(), info.clone(), msg)
+            .unwrap();
+
+        let editors = metadata
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::Editors {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        match editors {
+            QueryResp::Editors(editors) => {
+                assert_eq!(editors.len(), 1);
+                assert_eq!(editors[0].address, Addr::unchecked(USER));
+            }
+            _ => panic!(),
+        }
+
+        // a restricted editor can only touch their fields
+        let editor = mock_info(USER, &[]);
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                editor.clone(),
+                ExecuteMsg::PatchMetadata(json!({ "description": "edited" })),
+            )
+            .unwrap();
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                editor.clone(),
+                ExecuteMsg::SetMetadata(collection("second")),
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::FieldNotEditable { field } if field == "name"));
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                editor.clone(),
+                ExecuteMsg::SetTokenMetadata {
+                    token_id: "1".to_string(),
+                    metadata: json!({ "seat": 4 }),
+                },
+            )
+            .unwrap();
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                editor.clone(),
+                ExecuteMsg::UpdateTokenMetadata {
+                    token_id: "1".to_string(),
+                    patch: json!({ "gate": "south" }),
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::FieldNotEditable { .. }));
+
+        // editors cannot freeze or roll back
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                editor.clone(),
+                ExecuteMsg::FreezeMetadata { fields: None },
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::Unauthorized {}));
+
+        metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info,
+                ExecuteMsg::RemoveEditor {
+                    address: Addr::unchecked(USER),
+                },
+            )
+            .unwrap();
+        let err = metadata
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                editor,
+                ExecuteMsg::PatchMetadata(json!({ "description": "again" })),
+            )
+            .unwrap_err();
+        assert!(matches!(err, MetadataError::Unauthorized {}));
+    }
+
+    #[test]
+    fn editors_under_shared_control() {
+        let co_owners = |deps: &mut DepsMut| {
+            Ownable::default()
+                .execute(
+                    deps,
+                    mock_env(),
+                    mock_info(CREATOR, &[]),
+                    ownable::ExecuteMsg::SetCoOwners {
+                        owners: vec![Addr::unchecked(CREATOR), Addr::unchecked(EDITOR)],
+                        threshold: 2,
+                    },
+                )
+                .unwrap();
+        };
+        let timelock = |deps: &mut DepsMut| {
+            Timelock::default()
+                .execute(
+                    deps,
+                    mock_env(),
+                    mock_info(CREATOR, &[]),
+                    timelock::ExecuteMsg::EnableTimelock { min_delay: 3600 },
+                )
+                .unwrap();
+        };
+
+        for shared_control in [co_owners, timelock] {
+            let mut deps = mock_dependencies();
+            let env = mock_env();
+            let mut metadata = setup(&mut deps.as_mut());
+            metadata
+                .execute(
+                    &mut deps.as_mut(),
+                    env.clone(),
+                    mock_info(CREATOR, &[]),
+                    ExecuteMsg::AddEditor {
+                        address: Addr::unchecked(USER),
+                        fields: None,
+                    },
+                )
+                .unwrap();
+            shared_control(&mut deps.as_mut());
+
+            // editors can't get around the approvals or the delay
+            let err = metadata
+                .execute(
+                    &mut deps.as_mut(),
+                    env.clone(),
+                    mock_info(USER, &[]),
+                    ExecuteMsg::SetMetadata(collection("second")),
+                )
+                .unwrap_err();
+            assert!(matches!(err, MetadataError::Unauthorized {}));
+
+            // and edit again once control is back with a single owner
+            Ownable::default()
+                .execute(
+                    &mut deps.as_mut(),
+                    env.clone(),
+                    mock_info(env.contract.address.as_str(), &[]),
+                    ownable::ExecuteMsg::SetOwner(Addr::unchecked(CREATOR)),
+                )
+                .unwrap();
+            metadata
+                .execute(
+                    &mut deps.as_mut(),
+                    env,
+                    mock_info(USER, &[]),
+                    ExecuteMsg::SetMetadata(collection("second")),
+                )
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn separate_storage_keys() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut collection_metadata = setup(&mut deps.as_mut());
+        let ownable = Rc::new(RefCell::new(Ownable::default()));
+        let keys = MetadataKeys {
+            metadata: "ticket_metadata",
+            history: "ticket_metadata_history",
+            version: "ticket_metadata_version",
+            frozen: "ticket_metadata_frozen",
+            frozen_fields: "ticket_metadata_frozen_fields",
+            token_metadata: "ticket_token_metadata",
+            editors: "ticket_metadata_editors",
+        };
+        let mut ticket_metadata: Metadata<Collection> = Metadata::new(keys, ownable, None);
+        ticket_metadata
+            .instantiate(
+                &mut deps.as_mut(),
+                &env,
+                &info,
+                InstantiateMsg {
+                    metadata: collection("ticket"),
+                },
+            )
+            .unwrap();
+
+        // freezing one module leaves the other editable
+        collection_metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::FreezeMetadata { fields: None },
+            )
+            .unwrap();
+        ticket_metadata
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info,
+                ExecuteMsg::SetMetadata(collection("second ticket")),
+            )
+            .unwrap();
+
+        let current = collection_metadata
+            .query(&deps.as_ref(), env.clone(), QueryMsg::GetMetadata {})
+            .unwrap();
+        assert_eq!(current, QueryResp::Metadata(collection("first")));
+        let current = ticket_metadata
+            .query(&deps.as_ref(), env, QueryMsg::GetMetadata {})
+            .unwrap();
+        assert_eq!(current, QueryResp::Metadata(collection("second ticket")));
+    }
+}
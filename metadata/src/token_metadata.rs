@@ -0,0 +1,87 @@
+use cosmwasm_std::{Deps, DepsMut, StdResult};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{merge_patch, to_json_value, Metadata, MetadataError};
+
+impl<'a, T> Metadata<'a, T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    pub fn get_token_overrides(&self, deps: &Deps, token_id: &str) -> StdResult<Option<Value>> {
+        self.token_metadata.may_load(deps.storage, token_id)
+    }
+
+    // get_token_metadata returns the collection metadata with the token's
+    // overrides merged on top of it
+    pub fn get_token_metadata(&self, deps: &Deps, token_id: &str) -> StdResult<Value> {
+        let mut doc = to_json_value(&self.metadata.load(deps.storage)?)?;
+        if let Some(overrides) = self.get_token_overrides(deps, token_id)? {
+            merge_patch(&mut doc, &overrides);
+        }
+        Ok(doc)
+    }
+
+    // set_token_metadata replaces the token's overrides
+    pub fn set_token_metadata(
+        &self,
+        deps: &mut DepsMut,
+        token_id: &str,
+        overrides: Value,
+    ) -> Result<(), MetadataError> {
+        if !overrides.is_object() {
+            return Err(MetadataError::InvalidTokenMetadata {});
+        }
+        let current = self.get_token_overrides(&deps.as_ref(), token_id)?;
+        self.check_frozen_overrides(&deps.as_ref(), current.as_ref(), Some(&overrides))?;
+        self.token_metadata
+            .save(deps.storage, token_id, &overrides)?;
+        Ok(())
+    }
+
+    // update_token_metadata merge patches the token's existing overrides
+    pub fn update_token_metadata(
+        &self,
+        deps: &mut DepsMut,
+        token_id: &str,
+        patch: &Value,
+    ) -> Result<(), MetadataError> {
+        let mut overrides = self
+            .get_token_overrides(&deps.as_ref(), token_id)?
+            .unwrap_or_else(|| Value::Object(Default::default()));
+        merge_patch(&mut overrides, patch);
+        self.set_token_metadata(deps, token_id, overrides)
+    }
+
+    pub fn delete_token_metadata(
+        &self,
+        deps: &mut DepsMut,
+        token_id: &str,
+    ) -> Result<(), MetadataError> {
+        let current = self.get_token_overrides(&deps.as_ref(), token_id)?;
+        self.check_frozen_overrides(&deps.as_ref(), current.as_ref(), None)?;
+        self.token_metadata.remove(deps.storage, token_id);
+        Ok(())
+    }
+
+    // check_frozen_overrides keeps the token's frozen fields from changing,
+    // whether by adding, changing or removing their overrides
+    fn check_frozen_overrides(
+        &self,
+        deps: &Deps,
+        current: Option<&Value>,
+        new: Option<&Value>,
+    ) -> Result<(), MetadataError> {
+        if self.is_frozen(deps)? {
+            return Err(MetadataError::Frozen {});
+        }
+        for field in self.frozen_fields(deps)? {
+            let current = current.and_then(|overrides| overrides.get(&field));
+            if current != new.and_then(|overrides| overrides.get(&field)) {
+                return Err(MetadataError::FieldFrozen { field });
+            }
+        }
+        Ok(())
+    }
+}
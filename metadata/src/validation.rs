@@ -0,0 +1,66 @@
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    // field is the metadata field the error refers to, empty for the whole document
+    pub field: String,
+    pub reason: String,
+}
+
+impl ValidationError {
+    pub fn new(field: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.field.is_empty() {
+            write!(f, "{}", self.reason)
+        } else {
+            write!(f, "{}: {}", self.field, self.reason)
+        }
+    }
+}
+
+// MetadataValidator checks metadata before it is stored, returning every rule
+// it breaks
+pub trait MetadataValidator<T> {
+    fn validate(&self, metadata: &T) -> Result<(), Vec<ValidationError>>;
+}
+
+impl<T, F> MetadataValidator<T> for F
+where
+    F: Fn(&T) -> Result<(), Vec<ValidationError>>,
+{
+    fn validate(&self, metadata: &T) -> Result<(), Vec<ValidationError>> {
+        self(metadata)
+    }
+}
+
+// MaxSize limits the size of the JSON encoded metadata
+pub struct MaxSize {
+    pub max_bytes: usize,
+}
+
+impl<T> MetadataValidator<T> for MaxSize
+where
+    T: Serialize,
+{
+    fn validate(&self, metadata: &T) -> Result<(), Vec<ValidationError>> {
+        let size = serde_json::to_vec(metadata)
+            .map_err(|e| vec![ValidationError::new("", e.to_string())])?
+            .len();
+        if size > self.max_bytes {
+            return Err(vec![ValidationError::new(
+                "",
+                format!("{} bytes exceeds the maximum of {}", size, self.max_bytes),
+            )]);
+        }
+        Ok(())
+    }
+}
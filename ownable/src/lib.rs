@@ -1,35 +1,125 @@
+pub mod multisig;
+pub mod roles;
+mod test;
+pub mod timelock;
+
 use cosmwasm_std::StdError;
-use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, StdResult};
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, StdResult, Timestamp};
+use cw_storage_plus::{Bound, Item, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::fmt::Debug;
+use std::rc::{Rc, Weak};
 use thiserror::Error;
 
+use crate::multisig::{Action, CoOwners, ACTIONS_STATE, ACTION_COUNT_STATE, CO_OWNERS_STATE};
+use crate::roles::{RoleGrant, Roles};
+use crate::timelock::{TimelockConfig, TIMELOCK_CONFIG};
 use crate::OwnableError::Unauthorized;
 use burnt_glue::module::Module;
 use burnt_glue::response::Response;
 
 pub const OWNER_STATE: Item<Addr> = Item::new("owner");
+pub const PENDING_OWNER_STATE: Item<PendingOwner> = Item::new("pending_owner");
+pub const OWNER_HISTORY_STATE: Map<u64, OwnerRecord> = Map::new("owner_history");
+pub const PAYOUT_STATE: Item<Addr> = Item::new("payout");
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
 
 pub struct Ownable<'a> {
     pub owner: Item<'a, Addr>,
+    pub pending_owner: Item<'a, PendingOwner>,
+    pub owner_history: Map<'a, u64, OwnerRecord>,
+    pub payout: Item<'a, Addr>,
+    pub co_owners: Item<'a, CoOwners>,
+    pub actions: Map<'a, u64, Action>,
+    pub action_count: Item<'a, u64>,
+    pub timelock_config: Item<'a, TimelockConfig>,
+    // roles is the module granting the instantiation roles, held weakly as
+    // the roles module holds this one
+    roles: Option<Weak<RefCell<Roles<'a>>>>,
 }
 
 impl<'a> Default for Ownable<'a> {
     fn default() -> Self {
-        Self { owner: OWNER_STATE }
+        Self {
+            owner: OWNER_STATE,
+            pending_owner: PENDING_OWNER_STATE,
+            owner_history: OWNER_HISTORY_STATE,
+            payout: PAYOUT_STATE,
+            co_owners: CO_OWNERS_STATE,
+            actions: ACTIONS_STATE,
+            action_count: ACTION_COUNT_STATE,
+            timelock_config: TIMELOCK_CONFIG,
+            roles: None,
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-pub struct InstantiateMsg {}
+pub struct PendingOwner {
+    pub owner: Addr,
+    // expiry is the block time after which the proposal can no longer be accepted
+    pub expiry: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OwnerRecord {
+    // owner is None once ownership has been renounced
+    pub owner: Option<Addr>,
+    pub time: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    // owner defaults to the instantiating address, e.g. to let a factory
+    // deploy a collection owned by its creator
+    pub owner: Option<Addr>,
+    // roles are granted to their holders on instantiation
+    pub roles: Option<Vec<RoleGrant>>,
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
     SetOwner(Addr),
+    // ProposeOwner nominates a new owner, who has to accept before ownership moves
+    ProposeOwner {
+        owner: Addr,
+        expiry: Option<Timestamp>,
+    },
+    // AcceptOwnership is sent by the pending owner to complete the transfer
+    AcceptOwnership {},
+    // CancelProposal withdraws the pending ownership transfer
+    CancelProposal {},
+    // SetPayout sets the address receiving sale proceeds
+    SetPayout {
+        address: Addr,
+    },
+    // RenounceOwnership permanently leaves the contract without an owner
+    RenounceOwnership {},
+    // SetCoOwners puts the contract under M-of-N control of the co-owners
+    SetCoOwners {
+        owners: Vec<Addr>,
+        threshold: u32,
+    },
+    // ProposeAction queues an execute message of this contract for co-owner approval
+    ProposeAction {
+        msg: Binary,
+    },
+    // ApproveAction adds the sender's approval to the action
+    ApproveAction {
+        id: u64,
+    },
+    // ExecuteAction runs the action once it has enough approvals
+    ExecuteAction {
+        id: u64,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -37,6 +127,28 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     // IsOwner returns true if the address matches the owner
     IsOwner(Addr),
+    // GetOwner returns the current owner, None once ownership is renounced
+    GetOwner {},
+    // OwnerHistory returns every owner change with its block time
+    OwnerHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    // GetPendingOwner returns the proposed owner, if any
+    GetPendingOwner {},
+    // GetPayout returns the address receiving sale proceeds
+    GetPayout {},
+    // GetCoOwners returns the co-owners and approval threshold, if any
+    GetCoOwners {},
+    // GetAction returns a proposed action
+    GetAction {
+        id: u64,
+    },
+    // ListActions returns proposed actions by id
+    ListActions {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -44,6 +156,13 @@ pub enum QueryMsg {
 pub enum QueryResp {
     // IsOwner returns true if the address matches the owner
     IsOwner(bool),
+    Owner(Option<Addr>),
+    OwnerHistory(Vec<(u64, OwnerRecord)>),
+    PendingOwner(Option<PendingOwner>),
+    Payout(Option<Addr>),
+    CoOwners(Option<CoOwners>),
+    Action(Action),
+    Actions(Vec<(u64, Action)>),
 }
 
 #[derive(Error, Debug)]
@@ -54,6 +173,54 @@ pub enum OwnableError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Ownership renounced")]
+    OwnershipRenounced {},
+
+    #[error("No pending owner")]
+    NoPendingOwner {},
+
+    #[error("Ownership proposal expired")]
+    ProposalExpired {},
+
+    #[error("Invalid expiry")]
+    InvalidExpiry {},
+
+    #[error("No roles module to grant roles with")]
+    NoRolesModule {},
+
+    #[error("Invalid approval threshold")]
+    InvalidThreshold {},
+
+    #[error("Duplicate co-owner {owner}")]
+    DuplicateCoOwner { owner: Addr },
+
+    #[error("Action already approved")]
+    AlreadyApproved {},
+
+    #[error("Action {id} already executed")]
+    ActionExecuted { id: u64 },
+
+    #[error("Action has {approvals} of {threshold} required approvals")]
+    ThresholdNotMet { approvals: u32, threshold: u32 },
+
+    #[error("Timelock not enabled")]
+    TimelockNotEnabled {},
+
+    #[error("Timelock already enabled")]
+    TimelockEnabled {},
+
+    #[error("Delay of {delay} seconds is too long")]
+    DelayTooLong { delay: u64 },
+
+    #[error("Delay below the minimum of {min_delay} seconds")]
+    InvalidDelay { min_delay: u64 },
+
+    #[error("Minimum delay must be above zero")]
+    InvalidMinDelay {},
+
+    #[error("Action not ready until {ready_at}")]
+    TimelockNotReady { ready_at: Timestamp },
+
     // #[error("{0}")]
     // SerdeJson(#[from] serde_json::Error),
     #[error("Custom Error val: {val:?}")]
@@ -63,18 +230,161 @@ pub enum OwnableError {
 }
 
 impl<'a> Ownable<'a> {
+    // set_roles hands over the composed roles module, the one roles given on
+    // instantiation are granted with
+    pub fn set_roles(&mut self, roles: &Rc<RefCell<Roles<'a>>>) {
+        self.roles = Some(Rc::downgrade(roles));
+    }
+
     pub fn get_owner(&self, deps: &Deps) -> StdResult<Addr> {
         self.owner.load(deps.storage)
     }
 
     pub fn is_owner(&self, deps: &Deps, addr: &Addr) -> StdResult<bool> {
-        self.owner.load(deps.storage).map(|owner| owner.eq(addr))
+        Ok(self
+            .owner
+            .may_load(deps.storage)?
+            .map(|owner| owner.eq(addr))
+            .unwrap_or(false))
     }
 
-    pub fn set_owner(&self, deps: &mut DepsMut, addr: &Addr) -> StdResult<()> {
+    pub fn is_renounced(&self, deps: &Deps) -> StdResult<bool> {
+        Ok(self.owner.may_load(deps.storage)?.is_none())
+    }
+
+    // check_owner errors if the address is not the owner or nobody owns the contract
+    pub fn check_owner(&self, deps: &Deps, addr: &Addr) -> Result<(), OwnableError> {
+        match self.owner.may_load(deps.storage)? {
+            None => Err(OwnableError::OwnershipRenounced {}),
+            Some(owner) if owner != *addr => Err(Unauthorized {}),
+            Some(_) => Ok(()),
+        }
+    }
+
+    pub fn set_owner(&self, deps: &mut DepsMut, env: &Env, addr: &Addr) -> StdResult<()> {
         // validate Addr before saving
         deps.api.addr_validate(addr.as_str())?;
-        self.owner.save(deps.storage, addr)
+        // a direct transfer supersedes any outstanding proposal
+        self.pending_owner.remove(deps.storage);
+        // handing control back from the contract to an address ends co-owner
+        // control and the timelock, and with them the suspension of role
+        // holders
+        if *addr != env.contract.address {
+            self.co_owners.remove(deps.storage);
+            self.timelock_config.remove(deps.storage);
+        }
+        self.owner.save(deps.storage, addr)?;
+        self.record_owner(deps, env, Some(addr.clone()))
+    }
+
+    // renounce_ownership leaves sales running, their proceeds keep going to
+    // the payout address
+    pub fn renounce_ownership(&self, deps: &mut DepsMut, env: &Env) -> StdResult<()> {
+        self.keep_payout(deps)?;
+        self.pending_owner.remove(deps.storage);
+        self.owner.remove(deps.storage);
+        self.record_owner(deps, env, None)
+    }
+
+    pub fn owner_history(
+        &self,
+        deps: &Deps,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<(u64, OwnerRecord)>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+
+        self.owner_history
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect()
+    }
+
+    fn record_owner(&self, deps: &mut DepsMut, env: &Env, owner: Option<Addr>) -> StdResult<()> {
+        let last = self
+            .owner_history
+            .keys(deps.storage, None, None, Order::Descending)
+            .next()
+            .transpose()?;
+        let record = OwnerRecord {
+            owner,
+            time: env.block.time,
+        };
+        self.owner_history
+            .save(deps.storage, last.map_or(0, |id| id + 1), &record)
+    }
+
+    // get_payout returns the address receiving sale proceeds, the owner until
+    // one is set
+    pub fn get_payout(&self, deps: &Deps) -> StdResult<Option<Addr>> {
+        match self.payout.may_load(deps.storage)? {
+            Some(payout) => Ok(Some(payout)),
+            None => self.owner.may_load(deps.storage),
+        }
+    }
+
+    pub fn set_payout(&self, deps: &mut DepsMut, addr: &Addr) -> StdResult<()> {
+        deps.api.addr_validate(addr.as_str())?;
+        self.payout.save(deps.storage, addr)
+    }
+
+    // keep_payout stores the current owner as the payout address before
+    // ownership leaves it, so proceeds don't follow ownership to the contract
+    pub(crate) fn keep_payout(&self, deps: &mut DepsMut) -> StdResult<()> {
+        if self.payout.may_load(deps.storage)?.is_none() {
+            if let Some(owner) = self.owner.may_load(deps.storage)? {
+                self.payout.save(deps.storage, &owner)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_pending_owner(&self, deps: &Deps) -> StdResult<Option<PendingOwner>> {
+        self.pending_owner.may_load(deps.storage)
+    }
+
+    pub fn propose_owner(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        addr: &Addr,
+        expiry: Option<Timestamp>,
+    ) -> Result<(), OwnableError> {
+        deps.api.addr_validate(addr.as_str())?;
+        if let Some(expiry) = expiry {
+            if expiry <= env.block.time {
+                return Err(OwnableError::InvalidExpiry {});
+            }
+        }
+        let pending = PendingOwner {
+            owner: addr.clone(),
+            expiry,
+        };
+        self.pending_owner.save(deps.storage, &pending)?;
+        Ok(())
+    }
+
+    pub fn accept_ownership(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        sender: &Addr,
+    ) -> Result<(), OwnableError> {
+        let pending = self
+            .pending_owner
+            .may_load(deps.storage)?
+            .ok_or(OwnableError::NoPendingOwner {})?;
+        if pending.owner != *sender {
+            return Err(Unauthorized {});
+        }
+        if let Some(expiry) = pending.expiry {
+            if env.block.time >= expiry {
+                return Err(OwnableError::ProposalExpired {});
+            }
+        }
+        self.set_owner(deps, env, &pending.owner)?;
+        Ok(())
     }
 }
 
@@ -88,11 +398,28 @@ impl<'a> Module for Ownable<'a> {
     fn instantiate(
         &mut self,
         deps: &mut DepsMut,
-        _: &Env,
+        env: &Env,
         info: &MessageInfo,
-        _: Self::InstantiateMsg,
+        msg: Self::InstantiateMsg,
     ) -> Result<Response, Self::Error> {
-        self.owner.save(deps.storage, &info.sender)?;
+        let owner = match msg.owner {
+            Some(owner) => deps.api.addr_validate(owner.as_str())?,
+            None => info.sender.clone(),
+        };
+        self.set_owner(deps, env, &owner)?;
+
+        let grants = msg.roles.unwrap_or_default();
+        if !grants.is_empty() {
+            let roles = self
+                .roles
+                .as_ref()
+                .and_then(Weak::upgrade)
+                .ok_or(OwnableError::NoRolesModule {})?;
+            let roles = roles.borrow();
+            for grant in grants {
+                roles.grant_role(deps, grant.role, &grant.address)?;
+            }
+        }
 
         Ok(Response::new())
     }
@@ -100,7 +427,7 @@ impl<'a> Module for Ownable<'a> {
     fn execute(
         &mut self,
         deps: &mut DepsMut,
-        _: Env,
+        env: Env,
         info: MessageInfo,
         msg: Self::ExecuteMsg,
     ) -> Result<Response, Self::Error> {
@@ -109,14 +436,69 @@ impl<'a> Module for Ownable<'a> {
                 // validate Addr before saving
                 deps.api.addr_validate(owner.as_str())?;
 
-                let loaded_owner = self.owner.load(deps.storage).unwrap();
-                if info.sender != loaded_owner {
-                    Err(Unauthorized {})
-                } else {
-                    self.set_owner(deps, &owner)?;
-                    let resp = Response::new();
-                    Ok(resp)
+                self.check_owner(&deps.as_ref(), &info.sender)?;
+                self.set_owner(deps, &env, &owner)?;
+                let resp = Response::new();
+                Ok(resp)
+            }
+            ExecuteMsg::ProposeOwner { owner, expiry } => {
+                self.check_owner(&deps.as_ref(), &info.sender)?;
+                self.propose_owner(deps, &env, &owner, expiry)?;
+                Ok(Response::new()
+                    .add_attribute("action", "propose_owner")
+                    .add_attribute("pending_owner", owner))
+            }
+            ExecuteMsg::AcceptOwnership {} => {
+                self.accept_ownership(deps, &env, &info.sender)?;
+                Ok(Response::new()
+                    .add_attribute("action", "accept_ownership")
+                    .add_attribute("owner", info.sender))
+            }
+            ExecuteMsg::CancelProposal {} => {
+                self.check_owner(&deps.as_ref(), &info.sender)?;
+                if self.pending_owner.may_load(deps.storage)?.is_none() {
+                    return Err(OwnableError::NoPendingOwner {});
                 }
+                self.pending_owner.remove(deps.storage);
+                Ok(Response::new().add_attribute("action", "cancel_ownership_proposal"))
+            }
+            ExecuteMsg::SetPayout { address } => {
+                self.check_owner(&deps.as_ref(), &info.sender)?;
+                self.set_payout(deps, &address)?;
+                Ok(Response::new()
+                    .add_attribute("action", "set_payout")
+                    .add_attribute("payout", address))
+            }
+            ExecuteMsg::RenounceOwnership {} => {
+                self.check_owner(&deps.as_ref(), &info.sender)?;
+                self.renounce_ownership(deps, &env)?;
+                Ok(Response::new().add_attribute("action", "renounce_ownership"))
+            }
+            ExecuteMsg::SetCoOwners { owners, threshold } => {
+                self.check_owner(&deps.as_ref(), &info.sender)?;
+                self.set_co_owners(deps, &env, owners, threshold)?;
+                Ok(Response::new()
+                    .add_attribute("action", "set_co_owners")
+                    .add_attribute("threshold", threshold.to_string()))
+            }
+            ExecuteMsg::ProposeAction { msg } => {
+                let id = self.propose_action(deps, &info.sender, msg)?;
+                Ok(Response::new()
+                    .add_attribute("action", "propose_action")
+                    .add_attribute("action_id", id.to_string()))
+            }
+            ExecuteMsg::ApproveAction { id } => {
+                self.approve_action(deps, &info.sender, id)?;
+                Ok(Response::new()
+                    .add_attribute("action", "approve_action")
+                    .add_attribute("action_id", id.to_string()))
+            }
+            ExecuteMsg::ExecuteAction { id } => {
+                let msg = self.execute_action(deps, &env, &info.sender, id)?;
+                Ok(Response::new()
+                    .add_message(msg)
+                    .add_attribute("action", "execute_action")
+                    .add_attribute("action_id", id.to_string()))
             }
         }
     }
@@ -129,10 +511,37 @@ impl<'a> Module for Ownable<'a> {
     ) -> Result<Self::QueryResp, Self::Error> {
         match msg {
             QueryMsg::IsOwner(address) => {
-                let loaded_owner = self.owner.load(deps.storage).unwrap();
-                let resp = QueryResp::IsOwner(loaded_owner == address);
+                let resp = QueryResp::IsOwner(self.is_owner(deps, &address)?);
                 Ok(resp)
             }
+            QueryMsg::GetOwner {} => {
+                let owner = self.owner.may_load(deps.storage)?;
+                Ok(QueryResp::Owner(owner))
+            }
+            QueryMsg::OwnerHistory { start_after, limit } => {
+                let history = self.owner_history(deps, start_after, limit)?;
+                Ok(QueryResp::OwnerHistory(history))
+            }
+            QueryMsg::GetPendingOwner {} => {
+                let pending = self.get_pending_owner(deps)?;
+                Ok(QueryResp::PendingOwner(pending))
+            }
+            QueryMsg::GetPayout {} => {
+                let payout = self.get_payout(deps)?;
+                Ok(QueryResp::Payout(payout))
+            }
+            QueryMsg::GetCoOwners {} => {
+                let co_owners = self.get_co_owners(deps)?;
+                Ok(QueryResp::CoOwners(co_owners))
+            }
+            QueryMsg::GetAction { id } => {
+                let action = self.actions.load(deps.storage, id)?;
+                Ok(QueryResp::Action(action))
+            }
+            QueryMsg::ListActions { start_after, limit } => {
+                let actions = self.list_actions(deps, start_after, limit)?;
+                Ok(QueryResp::Actions(actions))
+            }
         }
     }
 }
@@ -0,0 +1,176 @@
+use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, Order, StdResult, WasmMsg};
+use cw_storage_plus::{Bound, Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{Ownable, OwnableError, DEFAULT_LIMIT, MAX_LIMIT};
+
+pub const CO_OWNERS_STATE: Item<CoOwners> = Item::new("co_owners");
+pub const ACTIONS_STATE: Map<u64, Action> = Map::new("owner_actions");
+pub const ACTION_COUNT_STATE: Item<u64> = Item::new("owner_action_count");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CoOwners {
+    pub owners: Vec<Addr>,
+    // threshold is the number of co-owner approvals an action needs to run
+    pub threshold: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Action {
+    pub proposer: Addr,
+    // msg is the execute message sent back to this contract once approved
+    pub msg: Binary,
+    pub approvals: Vec<Addr>,
+    pub executed: bool,
+}
+
+impl<'a> Ownable<'a> {
+    pub fn get_co_owners(&self, deps: &Deps) -> StdResult<Option<CoOwners>> {
+        self.co_owners.may_load(deps.storage)
+    }
+
+    pub fn is_co_owner(&self, deps: &Deps, addr: &Addr) -> StdResult<bool> {
+        Ok(self
+            .co_owners
+            .may_load(deps.storage)?
+            .map(|co_owners| co_owners.owners.contains(addr))
+            .unwrap_or(false))
+    }
+
+    // set_co_owners hands ownership to the contract itself, so owner gated
+    // messages can only be reached through approved actions, sale proceeds
+    // keep going to the payout address
+    pub fn set_co_owners(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        owners: Vec<Addr>,
+        threshold: u32,
+    ) -> Result<(), OwnableError> {
+        let mut validated: Vec<Addr> = vec![];
+        for owner in owners {
+            let owner = deps.api.addr_validate(owner.as_str())?;
+            if validated.contains(&owner) {
+                return Err(OwnableError::DuplicateCoOwner { owner });
+            }
+            validated.push(owner);
+        }
+        if threshold == 0 || threshold as usize > validated.len() {
+            return Err(OwnableError::InvalidThreshold {});
+        }
+
+        self.co_owners.save(
+            deps.storage,
+            &CoOwners {
+                owners: validated,
+                threshold,
+            },
+        )?;
+        self.keep_payout(deps)?;
+        self.set_owner(deps, env, &env.contract.address)?;
+        Ok(())
+    }
+
+    pub fn propose_action(
+        &self,
+        deps: &mut DepsMut,
+        proposer: &Addr,
+        msg: Binary,
+    ) -> Result<u64, OwnableError> {
+        if !self.is_co_owner(&deps.as_ref(), proposer)? {
+            return Err(OwnableError::Unauthorized {});
+        }
+        let id = self.action_count.may_load(deps.storage)?.unwrap_or(0) + 1;
+        self.action_count.save(deps.storage, &id)?;
+        let action = Action {
+            proposer: proposer.clone(),
+            msg,
+            approvals: vec![proposer.clone()],
+            executed: false,
+        };
+        self.actions.save(deps.storage, id, &action)?;
+        Ok(id)
+    }
+
+    pub fn approve_action(
+        &self,
+        deps: &mut DepsMut,
+        approver: &Addr,
+        id: u64,
+    ) -> Result<(), OwnableError> {
+        if !self.is_co_owner(&deps.as_ref(), approver)? {
+            return Err(OwnableError::Unauthorized {});
+        }
+        let mut action = self.actions.load(deps.storage, id)?;
+        if action.executed {
+            return Err(OwnableError::ActionExecuted { id });
+        }
+        if action.approvals.contains(approver) {
+            return Err(OwnableError::AlreadyApproved {});
+        }
+        action.approvals.push(approver.clone());
+        self.actions.save(deps.storage, id, &action)?;
+        Ok(())
+    }
+
+    // execute_action marks the action as executed and returns the message
+    // that dispatches it back to this contract
+    pub fn execute_action(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        sender: &Addr,
+        id: u64,
+    ) -> Result<WasmMsg, OwnableError> {
+        let co_owners = self
+            .co_owners
+            .may_load(deps.storage)?
+            .ok_or(OwnableError::Unauthorized {})?;
+        if !co_owners.owners.contains(sender) {
+            return Err(OwnableError::Unauthorized {});
+        }
+        let mut action = self.actions.load(deps.storage, id)?;
+        if action.executed {
+            return Err(OwnableError::ActionExecuted { id });
+        }
+
+        // only approvals of current co-owners count towards the threshold
+        let approvals = action
+            .approvals
+            .iter()
+            .filter(|approver| co_owners.owners.contains(approver))
+            .count() as u32;
+        if approvals < co_owners.threshold {
+            return Err(OwnableError::ThresholdNotMet {
+                approvals,
+                threshold: co_owners.threshold,
+            });
+        }
+
+        action.executed = true;
+        self.actions.save(deps.storage, id, &action)?;
+        Ok(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: action.msg,
+            funds: vec![],
+        })
+    }
+
+    pub fn list_actions(
+        &self,
+        deps: &Deps,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<(u64, Action)>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+
+        self.actions
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect()
+    }
+}
@@ -0,0 +1,240 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Order, StdResult};
+use cw_storage_plus::{Bound, Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::timelock::{TimelockConfig, TIMELOCK_CONFIG};
+use crate::{Ownable, OwnableError, DEFAULT_LIMIT, MAX_LIMIT};
+use burnt_glue::module::Module;
+use burnt_glue::response::Response;
+
+pub const ROLE_MEMBERS: Map<(&str, &Addr), bool> = Map::new("role_members");
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Minter,
+    Lister,
+    SaleAdmin,
+    AllowlistManager,
+    Redeemer,
+    MetadataEditor,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Minter => "minter",
+            Role::Lister => "lister",
+            Role::SaleAdmin => "sale_admin",
+            Role::AllowlistManager => "allowlist_manager",
+            Role::Redeemer => "redeemer",
+            Role::MetadataEditor => "metadata_editor",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct RoleGrant {
+    pub role: Role,
+    pub address: Addr,
+}
+
+pub struct Roles<'a> {
+    pub members: Map<'a, (&'a str, &'a Addr), bool>,
+    pub timelock_config: Item<'a, TimelockConfig>,
+    ownable: Rc<RefCell<Ownable<'a>>>,
+}
+
+impl<'a> Default for Roles<'a> {
+    fn default() -> Self {
+        Self {
+            members: ROLE_MEMBERS,
+            timelock_config: TIMELOCK_CONFIG,
+            ownable: Rc::new(RefCell::new(Ownable::default())),
+        }
+    }
+}
+
+impl<'a> Roles<'a> {
+    pub fn new(
+        members: Map<'a, (&'a str, &'a Addr), bool>,
+        ownable: Rc<RefCell<Ownable<'a>>>,
+    ) -> Self {
+        Self {
+            members,
+            timelock_config: TIMELOCK_CONFIG,
+            ownable,
+        }
+    }
+
+    pub fn has_role(&self, deps: &Deps, role: Role, addr: &Addr) -> StdResult<bool> {
+        Ok(self.members.has(deps.storage, (role.as_str(), addr)))
+    }
+
+    // is_authorized returns true if the address is the owner or holds the role
+    pub fn is_authorized(&self, deps: &Deps, role: Role, addr: &Addr) -> StdResult<bool> {
+        if self.ownable.borrow().is_owner(deps, addr)? {
+            return Ok(true);
+        }
+        if self.is_suspended(deps)? {
+            return Ok(false);
+        }
+        self.has_role(deps, role, addr)
+    }
+
+    // is_suspended returns true while owner gated messages need co-owner
+    // approval or have to wait out the timelock, role holders would otherwise
+    // get around it
+    pub fn is_suspended(&self, deps: &Deps) -> StdResult<bool> {
+        Ok(self.ownable.borrow().get_co_owners(deps)?.is_some()
+            || self.timelock_config.may_load(deps.storage)?.is_some())
+    }
+
+    pub fn grant_role(&self, deps: &mut DepsMut, role: Role, addr: &Addr) -> StdResult<()> {
+        deps.api.addr_validate(addr.as_str())?;
+        self.members
+            .save(deps.storage, (role.as_str(), addr), &true)
+    }
+
+    pub fn revoke_role(&self, deps: &mut DepsMut, role: Role, addr: &Addr) -> StdResult<()> {
+        self.members.remove(deps.storage, (role.as_str(), addr));
+        Ok(())
+    }
+
+    pub fn role_members(
+        &self,
+        deps: &Deps,
+        role: Role,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<Addr>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.as_ref().map(Bound::exclusive);
+
+        self.members
+            .prefix(role.as_str())
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    // GrantRole gives the address the role, owner only
+    GrantRole { role: Role, address: Addr },
+    // RevokeRole takes the role from the address, owner only
+    RevokeRole { role: Role, address: Addr },
+    // RenounceRole drops a role held by the sender
+    RenounceRole { role: Role },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    // HasRole returns whether or not the address holds the role
+    HasRole {
+        role: Role,
+        address: Addr,
+    },
+    // RoleMembers returns the addresses holding the role
+    RoleMembers {
+        role: Role,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryResp {
+    HasRole(bool),
+    RoleMembers(Vec<Addr>),
+}
+
+impl<'a> Module for Roles<'a> {
+    type InstantiateMsg = InstantiateMsg;
+    type ExecuteMsg = ExecuteMsg;
+    type QueryMsg = QueryMsg;
+    type QueryResp = QueryResp;
+    type Error = OwnableError;
+
+    fn instantiate(
+        &mut self,
+        _: &mut DepsMut,
+        _: &Env,
+        _: &MessageInfo,
+        _: Self::InstantiateMsg,
+    ) -> Result<Response, Self::Error> {
+        Ok(Response::new())
+    }
+
+    fn execute(
+        &mut self,
+        deps: &mut DepsMut,
+        _: Env,
+        info: MessageInfo,
+        msg: Self::ExecuteMsg,
+    ) -> Result<Response, Self::Error> {
+        match msg {
+            ExecuteMsg::GrantRole { role, address } => {
+                self.ownable
+                    .borrow()
+                    .check_owner(&deps.as_ref(), &info.sender)?;
+                self.grant_role(deps, role, &address)?;
+                Ok(Response::new()
+                    .add_attribute("action", "grant_role")
+                    .add_attribute("role", role.as_str())
+                    .add_attribute("address", address))
+            }
+            ExecuteMsg::RevokeRole { role, address } => {
+                self.ownable
+                    .borrow()
+                    .check_owner(&deps.as_ref(), &info.sender)?;
+                self.revoke_role(deps, role, &address)?;
+                Ok(Response::new()
+                    .add_attribute("action", "revoke_role")
+                    .add_attribute("role", role.as_str())
+                    .add_attribute("address", address))
+            }
+            ExecuteMsg::RenounceRole { role } => {
+                self.revoke_role(deps, role, &info.sender)?;
+                Ok(Response::new()
+                    .add_attribute("action", "renounce_role")
+                    .add_attribute("role", role.as_str())
+                    .add_attribute("address", info.sender))
+            }
+        }
+    }
+
+    fn query(
+        &self,
+        deps: &Deps,
+        _: Env,
+        msg: Self::QueryMsg,
+    ) -> Result<Self::QueryResp, Self::Error> {
+        match msg {
+            QueryMsg::HasRole { role, address } => {
+                let has_role = self.has_role(deps, role, &address)?;
+                Ok(QueryResp::HasRole(has_role))
+            }
+            QueryMsg::RoleMembers {
+                role,
+                start_after,
+                limit,
+            } => {
+                let members = self.role_members(deps, role, start_after, limit)?;
+                Ok(QueryResp::RoleMembers(members))
+            }
+        }
+    }
+}
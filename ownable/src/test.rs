@@ -0,0 +1,800 @@
+#[cfg(test)]
+mod tests {
+    use burnt_glue::module::Module;
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, Binary, CosmosMsg, WasmMsg,
+    };
+    use cw_storage_plus::Map;
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::roles::{self, Role, RoleGrant, Roles};
+    use crate::timelock::{self, Timelock};
+    use crate::{ExecuteMsg, InstantiateMsg, Ownable, OwnableError, QueryMsg, QueryResp};
+
+    const CREATOR: &str = "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z";
+    const NEW_OWNER: &str = "burnt188rjfzzrdxlus60zgnrvs4rg0l73hct3mlvdpe";
+
+    #[test]
+    fn two_step_transfer() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut ownable = Ownable::default();
+        ownable
+            .instantiate(&mut deps.as_mut(), &env, &info, InstantiateMsg::default())
+            .unwrap();
+
+        // only the owner can propose
+        let msg = ExecuteMsg::ProposeOwner {
+            owner: Addr::unchecked(NEW_OWNER),
+            expiry: None,
+        };
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(NEW_OWNER, &[]),
+                msg.clone(),
+            )
+            .expect_err("proposal from non owner");
+        ownable
+            .execute(&mut deps.as_mut(), env.clone(), info.clone(), msg)
+            .unwrap();
+
+        // proposing does not move ownership
+        let owner = ownable
+            .query(&deps.as_ref(), env.clone(), QueryMsg::GetOwner {})
+            .unwrap();
+        assert_eq!(owner, QueryResp::Owner(Some(Addr::unchecked(CREATOR))));
+        let pending = ownable
+            .query(&deps.as_ref(), env.clone(), QueryMsg::GetPendingOwner {})
+            .unwrap();
+        match pending {
+            QueryResp::PendingOwner(Some(pending)) => {
+                assert_eq!(pending.owner, Addr::unchecked(NEW_OWNER))
+            }
+            _ => panic!(),
+        }
+
+        // only the pending owner can accept
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::AcceptOwnership {},
+            )
+            .expect_err("accept from non pending owner");
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(NEW_OWNER, &[]),
+                ExecuteMsg::AcceptOwnership {},
+            )
+            .unwrap();
+
+        let owner = ownable
+            .query(&deps.as_ref(), env.clone(), QueryMsg::GetOwner {})
+            .unwrap();
+        assert_eq!(owner, QueryResp::Owner(Some(Addr::unchecked(NEW_OWNER))));
+        let pending = ownable
+            .query(&deps.as_ref(), env, QueryMsg::GetPendingOwner {})
+            .unwrap();
+        assert_eq!(pending, QueryResp::PendingOwner(None));
+    }
+
+    #[test]
+    fn expired_and_cancelled_proposals() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut ownable = Ownable::default();
+        ownable
+            .instantiate(&mut deps.as_mut(), &env, &info, InstantiateMsg::default())
+            .unwrap();
+
+        // expiry has to be in the future
+        let msg = ExecuteMsg::ProposeOwner {
+            owner: Addr::unchecked(NEW_OWNER),
+            expiry: Some(env.block.time),
+        };
+        match ownable.execute(&mut deps.as_mut(), env.clone(), info.clone(), msg) {
+            Err(OwnableError::InvalidExpiry {}) => {}
+            _ => panic!(),
+        }
+
+        let msg = ExecuteMsg::ProposeOwner {
+            owner: Addr::unchecked(NEW_OWNER),
+            expiry: Some(env.block.time.plus_seconds(100)),
+        };
+        ownable
+            .execute(&mut deps.as_mut(), env.clone(), info.clone(), msg)
+            .unwrap();
+
+        // the proposal can't be accepted once it expired
+        env.block.time = env.block.time.plus_seconds(100);
+        match ownable.execute(
+            &mut deps.as_mut(),
+            env.clone(),
+            mock_info(NEW_OWNER, &[]),
+            ExecuteMsg::AcceptOwnership {},
+        ) {
+            Err(OwnableError::ProposalExpired {}) => {}
+            _ => panic!(),
+        }
+
+        // the owner can withdraw the proposal
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::CancelProposal {},
+            )
+            .unwrap();
+        match ownable.execute(
+            &mut deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::CancelProposal {},
+        ) {
+            Err(OwnableError::NoPendingOwner {}) => {}
+            _ => panic!(),
+        }
+        match ownable.execute(
+            &mut deps.as_mut(),
+            env,
+            mock_info(NEW_OWNER, &[]),
+            ExecuteMsg::AcceptOwnership {},
+        ) {
+            Err(OwnableError::NoPendingOwner {}) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn grant_and_revoke_roles() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut ownable = Ownable::default();
+        ownable
+            .instantiate(&mut deps.as_mut(), &env, &info, InstantiateMsg::default())
+            .unwrap();
+        let mut roles = Roles::default();
+        let lister = Addr::unchecked(NEW_OWNER);
+
+        // only the owner can grant roles
+        let msg = roles::ExecuteMsg::GrantRole {
+            role: Role::Lister,
+            address: lister.clone(),
+        };
+        roles
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(NEW_OWNER, &[]),
+                msg.clone(),
+            )
+            .expect_err("grant from non owner");
+        roles
+            .execute(&mut deps.as_mut(), env.clone(), info.clone(), msg)
+            .unwrap();
+
+        let has_role = roles
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                roles::QueryMsg::HasRole {
+                    role: Role::Lister,
+                    address: lister.clone(),
+                },
+            )
+            .unwrap();
+        assert_eq!(has_role, roles::QueryResp::HasRole(true));
+        assert!(!roles
+            .has_role(&deps.as_ref(), Role::SaleAdmin, &lister)
+            .unwrap());
+
+        // the owner is authorized for every role
+        assert!(roles
+            .is_authorized(&deps.as_ref(), Role::SaleAdmin, &Addr::unchecked(CREATOR))
+            .unwrap());
+        assert!(roles
+            .is_authorized(&deps.as_ref(), Role::Lister, &lister)
+            .unwrap());
+
+        let members = roles
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                roles::QueryMsg::RoleMembers {
+                    role: Role::Lister,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(members, roles::QueryResp::RoleMembers(vec![lister.clone()]));
+
+        roles
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                info,
+                roles::ExecuteMsg::RevokeRole {
+                    role: Role::Lister,
+                    address: lister.clone(),
+                },
+            )
+            .unwrap();
+        assert!(!roles
+            .is_authorized(&deps.as_ref(), Role::Lister, &lister)
+            .unwrap());
+    }
+
+    #[test]
+    fn co_owner_actions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut ownable = Ownable::default();
+        ownable
+            .instantiate(&mut deps.as_mut(), &env, &info, InstantiateMsg::default())
+            .unwrap();
+
+        let co_owners = vec![
+            Addr::unchecked(CREATOR),
+            Addr::unchecked(NEW_OWNER),
+            Addr::unchecked("third_owner"),
+        ];
+        let msg = ExecuteMsg::SetCoOwners {
+            owners: co_owners.clone(),
+            threshold: 4,
+        };
+        match ownable.execute(&mut deps.as_mut(), env.clone(), info.clone(), msg) {
+            Err(OwnableError::InvalidThreshold {}) => {}
+            _ => panic!(),
+        }
+        let msg = ExecuteMsg::SetCoOwners {
+            owners: co_owners,
+            threshold: 2,
+        };
+        ownable
+            .execute(&mut deps.as_mut(), env.clone(), info.clone(), msg)
+            .unwrap();
+
+        // the contract now owns itself, so the creator can't act alone
+        assert!(ownable
+            .is_owner(&deps.as_ref(), &env.contract.address)
+            .unwrap());
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::SetOwner(Addr::unchecked(CREATOR)),
+            )
+            .expect_err("owner gated message from co-owner");
+
+        // sale proceeds keep going to the previous owner
+        match ownable
+            .query(&deps.as_ref(), env.clone(), QueryMsg::GetPayout {})
+            .unwrap()
+        {
+            QueryResp::Payout(Some(payout)) => assert_eq!(payout, Addr::unchecked(CREATOR)),
+            _ => panic!(),
+        }
+
+        // role holders can't act without approval either
+        let roles = Roles::default();
+        let sale_admin = Addr::unchecked("sale_admin");
+        roles
+            .grant_role(&mut deps.as_mut(), Role::SaleAdmin, &sale_admin)
+            .unwrap();
+        assert!(!roles
+            .is_authorized(&deps.as_ref(), Role::SaleAdmin, &sale_admin)
+            .unwrap());
+
+        let set_owner = Binary::from(
+            serde_json::to_vec(&ExecuteMsg::SetOwner(Addr::unchecked(NEW_OWNER))).unwrap(),
+        );
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info("stranger", &[]),
+                ExecuteMsg::ProposeAction {
+                    msg: set_owner.clone(),
+                },
+            )
+            .expect_err("proposal from non co-owner");
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::ProposeAction {
+                    msg: set_owner.clone(),
+                },
+            )
+            .unwrap();
+
+        // a single approval is below the threshold
+        match ownable.execute(
+            &mut deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::ExecuteAction { id: 1 },
+        ) {
+            Err(OwnableError::ThresholdNotMet {
+                approvals: 1,
+                threshold: 2,
+            }) => {}
+            _ => panic!(),
+        }
+        match ownable.execute(
+            &mut deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::ApproveAction { id: 1 },
+        ) {
+            Err(OwnableError::AlreadyApproved {}) => {}
+            _ => panic!(),
+        }
+
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(NEW_OWNER, &[]),
+                ExecuteMsg::ApproveAction { id: 1 },
+            )
+            .unwrap();
+        let resp = ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::ExecuteAction { id: 1 },
+            )
+            .unwrap();
+        assert_eq!(resp.response.messages.len(), 1);
+        assert_eq!(
+            resp.response.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: set_owner,
+                funds: vec![],
+            })
+        );
+
+        // actions only run once
+        match ownable.execute(
+            &mut deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::ExecuteAction { id: 1 },
+        ) {
+            Err(OwnableError::ActionExecuted { id: 1 }) => {}
+            _ => panic!(),
+        }
+        let actions = ownable
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::ListActions {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        match actions {
+            QueryResp::Actions(actions) => {
+                assert_eq!(actions.len(), 1);
+                assert!(actions[0].1.executed);
+            }
+            _ => panic!(),
+        }
+
+        // the dispatched message hands control back to a single owner
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(env.contract.address.as_str(), &[]),
+                ExecuteMsg::SetOwner(Addr::unchecked(NEW_OWNER)),
+            )
+            .unwrap();
+        assert!(ownable
+            .is_owner(&deps.as_ref(), &Addr::unchecked(NEW_OWNER))
+            .unwrap());
+        assert_eq!(ownable.get_co_owners(&deps.as_ref()).unwrap(), None);
+        assert!(roles
+            .is_authorized(&deps.as_ref(), Role::SaleAdmin, &sale_admin)
+            .unwrap());
+        match ownable.execute(
+            &mut deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::ProposeAction {
+                msg: Binary::default(),
+            },
+        ) {
+            Err(OwnableError::Unauthorized {}) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn timelocked_actions() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut ownable = Ownable::default();
+        ownable
+            .instantiate(&mut deps.as_mut(), &env, &info, InstantiateMsg::default())
+            .unwrap();
+        let mut timelock = Timelock::default();
+
+        let sale_admin = Addr::unchecked("sale_admin");
+        Roles::default()
+            .grant_role(&mut deps.as_mut(), Role::SaleAdmin, &sale_admin)
+            .unwrap();
+
+        // a timelock without delay holds nothing back
+        match timelock.execute(
+            &mut deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            timelock::ExecuteMsg::EnableTimelock { min_delay: 0 },
+        ) {
+            Err(OwnableError::InvalidMinDelay {}) => {}
+            _ => panic!(),
+        }
+
+        // only the owner can enable the timelock
+        let msg = timelock::ExecuteMsg::EnableTimelock { min_delay: 3600 };
+        timelock
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(NEW_OWNER, &[]),
+                msg.clone(),
+            )
+            .expect_err("enable from non owner");
+        timelock
+            .execute(&mut deps.as_mut(), env.clone(), info.clone(), msg)
+            .unwrap();
+        assert!(ownable
+            .is_owner(&deps.as_ref(), &env.contract.address)
+            .unwrap());
+
+        // proceeds keep going to the previous owner and role holders have to
+        // wait for the timelock too
+        assert_eq!(
+            ownable.get_payout(&deps.as_ref()).unwrap(),
+            Some(Addr::unchecked(CREATOR))
+        );
+        assert!(!Roles::default()
+            .is_authorized(&deps.as_ref(), Role::SaleAdmin, &sale_admin)
+            .unwrap());
+
+        // the delay can only be changed by a queued action, and not to zero
+        match timelock.execute(
+            &mut deps.as_mut(),
+            env.clone(),
+            mock_info(env.contract.address.as_str(), &[]),
+            timelock::ExecuteMsg::SetMinDelay { min_delay: 0 },
+        ) {
+            Err(OwnableError::InvalidMinDelay {}) => {}
+            _ => panic!(),
+        }
+
+        let set_owner = Binary::from(
+            serde_json::to_vec(&ExecuteMsg::SetOwner(Addr::unchecked(NEW_OWNER))).unwrap(),
+        );
+        match timelock.execute(
+            &mut deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            timelock::ExecuteMsg::QueueAction {
+                msg: set_owner.clone(),
+                delay: Some(60),
+            },
+        ) {
+            Err(OwnableError::InvalidDelay { min_delay: 3600 }) => {}
+            _ => panic!(),
+        }
+        match timelock.execute(
+            &mut deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            timelock::ExecuteMsg::QueueAction {
+                msg: set_owner.clone(),
+                delay: Some(u64::MAX),
+            },
+        ) {
+            Err(OwnableError::DelayTooLong { delay: u64::MAX }) => {}
+            _ => panic!(),
+        }
+        timelock
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                timelock::ExecuteMsg::QueueAction {
+                    msg: set_owner.clone(),
+                    delay: None,
+                },
+            )
+            .unwrap();
+
+        // anyone can see what is queued
+        let queued = timelock
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                timelock::QueryMsg::ListQueuedActions {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        match queued {
+            timelock::QueryResp::QueuedActions(actions) => {
+                assert_eq!(actions.len(), 1);
+                assert_eq!(actions[0].1.ready_at, env.block.time.plus_seconds(3600));
+            }
+            _ => panic!(),
+        }
+
+        // the action can't run before the delay has passed
+        let stranger = mock_info("stranger", &[]);
+        match timelock.execute(
+            &mut deps.as_mut(),
+            env.clone(),
+            stranger.clone(),
+            timelock::ExecuteMsg::ExecuteAction { id: 1 },
+        ) {
+            Err(OwnableError::TimelockNotReady { .. }) => {}
+            _ => panic!(),
+        }
+        env.block.time = env.block.time.plus_seconds(3600);
+        let resp = timelock
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                stranger,
+                timelock::ExecuteMsg::ExecuteAction { id: 1 },
+            )
+            .unwrap();
+        assert_eq!(
+            resp.response.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: set_owner.clone(),
+                funds: vec![],
+            })
+        );
+
+        // the admin can cancel queued actions
+        timelock
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                timelock::ExecuteMsg::QueueAction {
+                    msg: set_owner,
+                    delay: None,
+                },
+            )
+            .unwrap();
+        timelock
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(NEW_OWNER, &[]),
+                timelock::ExecuteMsg::CancelAction { id: 2 },
+            )
+            .expect_err("cancel from non admin");
+        timelock
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                timelock::ExecuteMsg::CancelAction { id: 2 },
+            )
+            .unwrap();
+        let queued = timelock
+            .list_queued_actions(&deps.as_ref(), None, None)
+            .unwrap();
+        assert!(queued.is_empty());
+
+        // the dispatched SetOwner hands control back to a single owner, which
+        // ends the timelock
+        timelock
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info,
+                timelock::ExecuteMsg::QueueAction {
+                    msg: Binary::default(),
+                    delay: None,
+                },
+            )
+            .unwrap();
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(env.contract.address.as_str(), &[]),
+                ExecuteMsg::SetOwner(Addr::unchecked(NEW_OWNER)),
+            )
+            .unwrap();
+        assert!(ownable
+            .is_owner(&deps.as_ref(), &Addr::unchecked(NEW_OWNER))
+            .unwrap());
+        assert_eq!(timelock.get_config(&deps.as_ref()).unwrap(), None);
+        assert!(Roles::default()
+            .is_authorized(&deps.as_ref(), Role::SaleAdmin, &sale_admin)
+            .unwrap());
+        env.block.time = env.block.time.plus_seconds(3600);
+        match timelock.execute(
+            &mut deps.as_mut(),
+            env,
+            mock_info("stranger", &[]),
+            timelock::ExecuteMsg::ExecuteAction { id: 3 },
+        ) {
+            Err(OwnableError::TimelockNotEnabled {}) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn renounce_and_history() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut ownable = Ownable::default();
+        ownable
+            .instantiate(&mut deps.as_mut(), &env, &info, InstantiateMsg::default())
+            .unwrap();
+        let created_at = env.block.time;
+
+        env.block.time = env.block.time.plus_seconds(10);
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                info,
+                ExecuteMsg::SetOwner(Addr::unchecked(NEW_OWNER)),
+            )
+            .unwrap();
+
+        env.block.time = env.block.time.plus_seconds(10);
+        let new_owner_info = mock_info(NEW_OWNER, &[]);
+        ownable
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                new_owner_info.clone(),
+                ExecuteMsg::RenounceOwnership {},
+            )
+            .unwrap();
+
+        let owner = ownable
+            .query(&deps.as_ref(), env.clone(), QueryMsg::GetOwner {})
+            .unwrap();
+        assert_eq!(owner, QueryResp::Owner(None));
+        let is_owner = ownable
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::IsOwner(Addr::unchecked(NEW_OWNER)),
+            )
+            .unwrap();
+        assert_eq!(is_owner, QueryResp::IsOwner(false));
+        let payout = ownable
+            .query(&deps.as_ref(), env.clone(), QueryMsg::GetPayout {})
+            .unwrap();
+        assert_eq!(payout, QueryResp::Payout(Some(Addr::unchecked(NEW_OWNER))));
+
+        // nobody can act as owner anymore
+        match ownable.execute(
+            &mut deps.as_mut(),
+            env.clone(),
+            new_owner_info,
+            ExecuteMsg::SetOwner(Addr::unchecked(NEW_OWNER)),
+        ) {
+            Err(OwnableError::OwnershipRenounced {}) => {}
+            _ => panic!(),
+        }
+
+        let history = ownable
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::OwnerHistory {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        match history {
+            QueryResp::OwnerHistory(history) => {
+                assert_eq!(history.len(), 3);
+                assert_eq!(history[0].1.owner, Some(Addr::unchecked(CREATOR)));
+                assert_eq!(history[0].1.time, created_at);
+                assert_eq!(history[1].1.owner, Some(Addr::unchecked(NEW_OWNER)));
+                assert_eq!(history[2].1.owner, None);
+                assert_eq!(history[2].1.time, env.block.time);
+            }
+            _ => panic!(),
+        }
+
+        // history is paginated by sequence number
+        let page = ownable
+            .owner_history(&deps.as_ref(), Some(0), Some(1))
+            .unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].0, 1);
+    }
+
+    #[test]
+    fn instantiate_with_owner_and_roles() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        // a factory instantiates the collection on behalf of its creator
+        let info = mock_info("factory", &[]);
+
+        let ownable = Rc::new(RefCell::new(Ownable::default()));
+        let msg = InstantiateMsg {
+            owner: Some(Addr::unchecked(CREATOR)),
+            roles: Some(vec![RoleGrant {
+                role: Role::Minter,
+                address: Addr::unchecked(NEW_OWNER),
+            }]),
+        };
+        // granting roles takes the composed roles module
+        let err = ownable
+            .borrow_mut()
+            .instantiate(&mut deps.as_mut(), &env, &info, msg.clone())
+            .unwrap_err();
+        assert!(matches!(err, OwnableError::NoRolesModule {}));
+
+        let roles = Rc::new(RefCell::new(Roles::new(
+            Map::new("collection_roles"),
+            ownable.clone(),
+        )));
+        ownable.borrow_mut().set_roles(&roles);
+        ownable
+            .borrow_mut()
+            .instantiate(&mut deps.as_mut(), &env, &info, msg)
+            .unwrap();
+
+        let ownable = ownable.borrow();
+        let owner = ownable
+            .query(&deps.as_ref(), env, QueryMsg::GetOwner {})
+            .unwrap();
+        assert_eq!(owner, QueryResp::Owner(Some(Addr::unchecked(CREATOR))));
+        assert!(!ownable
+            .is_owner(&deps.as_ref(), &Addr::unchecked("factory"))
+            .unwrap());
+        assert!(roles
+            .borrow()
+            .has_role(&deps.as_ref(), Role::Minter, &Addr::unchecked(NEW_OWNER))
+            .unwrap());
+        assert!(!Roles::default()
+            .has_role(&deps.as_ref(), Role::Minter, &Addr::unchecked(NEW_OWNER))
+            .unwrap());
+    }
+}
@@ -0,0 +1,362 @@
+use cosmwasm_std::{
+    Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, StdResult, Timestamp, WasmMsg,
+};
+use cw_storage_plus::{Bound, Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{Ownable, OwnableError, DEFAULT_LIMIT, MAX_LIMIT};
+use burnt_glue::module::Module;
+use burnt_glue::response::Response;
+
+pub const TIMELOCK_CONFIG: Item<TimelockConfig> = Item::new("timelock_config");
+pub const QUEUED_ACTIONS: Map<u64, QueuedAction> = Map::new("timelock_actions");
+pub const QUEUED_ACTION_COUNT: Item<u64> = Item::new("timelock_action_count");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TimelockConfig {
+    // admin queues and cancels actions
+    pub admin: Addr,
+    // min_delay is the minimum number of seconds between queueing and execution
+    pub min_delay: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueuedAction {
+    // msg is the execute message sent back to this contract once ready
+    pub msg: Binary,
+    pub queued_at: Timestamp,
+    pub ready_at: Timestamp,
+}
+
+pub struct Timelock<'a> {
+    pub config: Item<'a, TimelockConfig>,
+    pub queued_actions: Map<'a, u64, QueuedAction>,
+    pub queued_action_count: Item<'a, u64>,
+    ownable: Rc<RefCell<Ownable<'a>>>,
+}
+
+impl<'a> Default for Timelock<'a> {
+    fn default() -> Self {
+        Self {
+            config: TIMELOCK_CONFIG,
+            queued_actions: QUEUED_ACTIONS,
+            queued_action_count: QUEUED_ACTION_COUNT,
+            ownable: Rc::new(RefCell::new(Ownable::default())),
+        }
+    }
+}
+
+impl<'a> Timelock<'a> {
+    pub fn new(ownable: Rc<RefCell<Ownable<'a>>>) -> Self {
+        Self {
+            config: TIMELOCK_CONFIG,
+            queued_actions: QUEUED_ACTIONS,
+            queued_action_count: QUEUED_ACTION_COUNT,
+            ownable,
+        }
+    }
+
+    pub fn get_config(&self, deps: &Deps) -> StdResult<Option<TimelockConfig>> {
+        self.config.may_load(deps.storage)
+    }
+
+    // enable makes the sender the timelock admin and hands ownership to the
+    // contract itself, so owner gated messages can only run through the queue,
+    // sale proceeds keep going to the payout address
+    pub fn enable(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        admin: &Addr,
+        min_delay: u64,
+    ) -> Result<(), OwnableError> {
+        if self.config.may_load(deps.storage)?.is_some() {
+            return Err(OwnableError::TimelockEnabled {});
+        }
+        check_min_delay(min_delay)?;
+        self.config.save(
+            deps.storage,
+            &TimelockConfig {
+                admin: admin.clone(),
+                min_delay,
+            },
+        )?;
+        let ownable = self.ownable.borrow();
+        ownable.keep_payout(deps)?;
+        ownable.set_owner(deps, env, &env.contract.address)?;
+        Ok(())
+    }
+
+    pub fn queue_action(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        sender: &Addr,
+        msg: Binary,
+        delay: Option<u64>,
+    ) -> Result<u64, OwnableError> {
+        let config = self.load_config_for_admin(&deps.as_ref(), sender)?;
+        let delay = delay.unwrap_or(config.min_delay);
+        if delay < config.min_delay {
+            return Err(OwnableError::InvalidDelay {
+                min_delay: config.min_delay,
+            });
+        }
+
+        // a delay past what a timestamp holds would overflow ready_at
+        let ready_at = delay
+            .checked_mul(1_000_000_000)
+            .and_then(|nanos| env.block.time.nanos().checked_add(nanos))
+            .map(Timestamp::from_nanos)
+            .ok_or(OwnableError::DelayTooLong { delay })?;
+
+        let id = self
+            .queued_action_count
+            .may_load(deps.storage)?
+            .unwrap_or(0)
+            + 1;
+        self.queued_action_count.save(deps.storage, &id)?;
+        let action = QueuedAction {
+            msg,
+            queued_at: env.block.time,
+            ready_at,
+        };
+        self.queued_actions.save(deps.storage, id, &action)?;
+        Ok(id)
+    }
+
+    pub fn cancel_action(
+        &self,
+        deps: &mut DepsMut,
+        sender: &Addr,
+        id: u64,
+    ) -> Result<(), OwnableError> {
+        self.load_config_for_admin(&deps.as_ref(), sender)?;
+        // make sure the action exists before removing it
+        self.queued_actions.load(deps.storage, id)?;
+        self.queued_actions.remove(deps.storage, id);
+        Ok(())
+    }
+
+    // execute_action dequeues a ready action and returns the message that
+    // dispatches it back to this contract
+    pub fn execute_action(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        id: u64,
+    ) -> Result<WasmMsg, OwnableError> {
+        // actions left queued once ownership moved on don't run anymore
+        if self.config.may_load(deps.storage)?.is_none() {
+            return Err(OwnableError::TimelockNotEnabled {});
+        }
+        let action = self.queued_actions.load(deps.storage, id)?;
+        if env.block.time < action.ready_at {
+            return Err(OwnableError::TimelockNotReady {
+                ready_at: action.ready_at,
+            });
+        }
+        self.queued_actions.remove(deps.storage, id);
+        Ok(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: action.msg,
+            funds: vec![],
+        })
+    }
+
+    pub fn list_queued_actions(
+        &self,
+        deps: &Deps,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<(u64, QueuedAction)>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+
+        self.queued_actions
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect()
+    }
+
+    fn load_config_for_admin(
+        &self,
+        deps: &Deps,
+        sender: &Addr,
+    ) -> Result<TimelockConfig, OwnableError> {
+        let config = self
+            .config
+            .may_load(deps.storage)?
+            .ok_or(OwnableError::TimelockNotEnabled {})?;
+        if config.admin != *sender {
+            return Err(OwnableError::Unauthorized {});
+        }
+        Ok(config)
+    }
+}
+
+// check_min_delay rejects a zero delay, which would leave the timelock
+// enabled without holding anything back
+fn check_min_delay(min_delay: u64) -> Result<(), OwnableError> {
+    if min_delay == 0 {
+        return Err(OwnableError::InvalidMinDelay {});
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    // EnableTimelock puts owner gated messages behind the timelock, owner only
+    EnableTimelock { min_delay: u64 },
+    // QueueAction schedules an execute message of this contract, admin only
+    QueueAction { msg: Binary, delay: Option<u64> },
+    // CancelAction drops a queued action, admin only
+    CancelAction { id: u64 },
+    // ExecuteAction runs a queued action once its delay has passed
+    ExecuteAction { id: u64 },
+    // SetAdmin changes the timelock admin, only through a queued action
+    SetAdmin { admin: Addr },
+    // SetMinDelay changes the minimum delay, only through a queued action
+    SetMinDelay { min_delay: u64 },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    // GetConfig returns the timelock admin and delay, if enabled
+    GetConfig {},
+    // GetQueuedAction returns a queued action
+    GetQueuedAction {
+        id: u64,
+    },
+    // ListQueuedActions returns queued actions by id
+    ListQueuedActions {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryResp {
+    Config(Option<TimelockConfig>),
+    QueuedAction(QueuedAction),
+    QueuedActions(Vec<(u64, QueuedAction)>),
+}
+
+impl<'a> Module for Timelock<'a> {
+    type InstantiateMsg = InstantiateMsg;
+    type ExecuteMsg = ExecuteMsg;
+    type QueryMsg = QueryMsg;
+    type QueryResp = QueryResp;
+    type Error = OwnableError;
+
+    fn instantiate(
+        &mut self,
+        _: &mut DepsMut,
+        _: &Env,
+        _: &MessageInfo,
+        _: Self::InstantiateMsg,
+    ) -> Result<Response, Self::Error> {
+        Ok(Response::new())
+    }
+
+    fn execute(
+        &mut self,
+        deps: &mut DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: Self::ExecuteMsg,
+    ) -> Result<Response, Self::Error> {
+        match msg {
+            ExecuteMsg::EnableTimelock { min_delay } => {
+                self.ownable
+                    .borrow()
+                    .check_owner(&deps.as_ref(), &info.sender)?;
+                self.enable(deps, &env, &info.sender, min_delay)?;
+                Ok(Response::new()
+                    .add_attribute("action", "enable_timelock")
+                    .add_attribute("min_delay", min_delay.to_string()))
+            }
+            ExecuteMsg::QueueAction { msg, delay } => {
+                let id = self.queue_action(deps, &env, &info.sender, msg, delay)?;
+                Ok(Response::new()
+                    .add_attribute("action", "queue_action")
+                    .add_attribute("action_id", id.to_string()))
+            }
+            ExecuteMsg::CancelAction { id } => {
+                self.cancel_action(deps, &info.sender, id)?;
+                Ok(Response::new()
+                    .add_attribute("action", "cancel_action")
+                    .add_attribute("action_id", id.to_string()))
+            }
+            ExecuteMsg::ExecuteAction { id } => {
+                let msg = self.execute_action(deps, &env, id)?;
+                Ok(Response::new()
+                    .add_message(msg)
+                    .add_attribute("action", "execute_queued_action")
+                    .add_attribute("action_id", id.to_string()))
+            }
+            ExecuteMsg::SetAdmin { admin } => {
+                if info.sender != env.contract.address {
+                    return Err(OwnableError::Unauthorized {});
+                }
+                deps.api.addr_validate(admin.as_str())?;
+                self.config
+                    .update::<_, OwnableError>(deps.storage, |mut config| {
+                        config.admin = admin.clone();
+                        Ok(config)
+                    })?;
+                Ok(Response::new()
+                    .add_attribute("action", "set_timelock_admin")
+                    .add_attribute("admin", admin))
+            }
+            ExecuteMsg::SetMinDelay { min_delay } => {
+                if info.sender != env.contract.address {
+                    return Err(OwnableError::Unauthorized {});
+                }
+                check_min_delay(min_delay)?;
+                self.config
+                    .update::<_, OwnableError>(deps.storage, |mut config| {
+                        config.min_delay = min_delay;
+                        Ok(config)
+                    })?;
+                Ok(Response::new()
+                    .add_attribute("action", "set_timelock_min_delay")
+                    .add_attribute("min_delay", min_delay.to_string()))
+            }
+        }
+    }
+
+    fn query(
+        &self,
+        deps: &Deps,
+        _: Env,
+        msg: Self::QueryMsg,
+    ) -> Result<Self::QueryResp, Self::Error> {
+        match msg {
+            QueryMsg::GetConfig {} => {
+                let config = self.get_config(deps)?;
+                Ok(QueryResp::Config(config))
+            }
+            QueryMsg::GetQueuedAction { id } => {
+                let action = self.queued_actions.load(deps.storage, id)?;
+                Ok(QueryResp::QueuedAction(action))
+            }
+            QueryMsg::ListQueuedActions { start_after, limit } => {
+                let actions = self.list_queued_actions(deps, start_after, limit)?;
+                Ok(QueryResp::QueuedActions(actions))
+            }
+        }
+    }
+}
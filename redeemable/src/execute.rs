@@ -1,20 +1,33 @@
 use crate::{errors::ContractError, Redeemable};
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 use cw_storage_plus::Item;
+use ownable::roles::{Role, Roles};
 use schemars::Set;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 impl<'a> Redeemable<'a> {
     pub fn new(item: Item<'a, Set<String>>) -> Self {
-        Self { locked_items: item }
+        Self {
+            locked_items: item,
+            roles: Rc::new(RefCell::new(Roles::default())),
+        }
     }
 
     pub fn redeem_item(
         &mut self,
         deps: &mut DepsMut,
         _env: Env,
-        _info: MessageInfo,
+        info: MessageInfo,
         token_id: String,
     ) -> Result<Response, ContractError> {
+        if !self
+            .roles
+            .borrow()
+            .is_authorized(&deps.as_ref(), Role::Redeemer, &info.sender)?
+        {
+            return Err(ContractError::Unauthorized);
+        }
         let mut locked_items = self.locked_items.load(deps.storage)?;
         locked_items.insert(token_id);
         self.locked_items.save(deps.storage, &locked_items)?;
@@ -0,0 +1,32 @@
+use cosmwasm_std::{DepsMut, StdResult};
+use std::cell::RefCell;
+use std::rc::Rc;
+use token::hooks::{TokenEvent, TokenHook};
+
+use crate::Redeemable;
+
+// RedemptionHook forgets the redemption of burnt tokens so a token minted
+// again under the same id starts unredeemed
+pub struct RedemptionHook<'a> {
+    pub redeemable: Rc<RefCell<Redeemable<'a>>>,
+}
+
+impl<'a> RedemptionHook<'a> {
+    pub fn new(redeemable: Rc<RefCell<Redeemable<'a>>>) -> Self {
+        Self { redeemable }
+    }
+}
+
+impl TokenHook for RedemptionHook<'_> {
+    fn on_token_event(&self, deps: &mut DepsMut, event: &TokenEvent) -> StdResult<()> {
+        if let TokenEvent::Burn { token_id, .. } = event {
+            let redeemable = self.redeemable.borrow();
+            if let Some(mut locked_items) = redeemable.locked_items.may_load(deps.storage)? {
+                if locked_items.remove(token_id) {
+                    redeemable.locked_items.save(deps.storage, &locked_items)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,26 +1,33 @@
 pub mod errors;
 pub mod execute;
+pub mod hooks;
 pub mod query;
 pub mod state;
+mod test;
 
 use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo};
 use cw_storage_plus::Item;
 use errors::ContractError;
+use ownable::roles::Roles;
 use schemars::{JsonSchema, Set};
 use serde::{Deserialize, Serialize};
 use state::LOCKED_ITEMS;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 use burnt_glue::module::Module;
 use burnt_glue::response::Response;
 
 pub struct Redeemable<'a> {
     pub locked_items: Item<'a, Set<String>>,
+    pub roles: Rc<RefCell<Roles<'a>>>,
 }
 
 impl Default for Redeemable<'_> {
     fn default() -> Self {
         Self {
             locked_items: LOCKED_ITEMS,
+            roles: Rc::new(RefCell::new(Roles::default())),
         }
     }
 }
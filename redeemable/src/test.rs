@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests {
+    use burnt_glue::module::Module;
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, Deps, DepsMut, Empty,
+    };
+    use cw721_base::{Extension, MintMsg};
+    use ownable::roles::{Role, Roles};
+    use ownable::Ownable;
+    use schemars::Set;
+    use std::{cell::RefCell, rc::Rc};
+    use token::msg::ExecuteMsg;
+    use token::Tokens;
+
+    use crate::errors::ContractError;
+    use crate::hooks::RedemptionHook;
+    use crate::{InstantiateMsg, QueryMsg, QueryResp, Redeemable};
+
+    const CREATOR: &str = "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z";
+    const USER: &str = "burnt188rjfzzrdxlus60zgnrvs4rg0l73hct3mlvdpe";
+    const REDEEMER: &str = "burnt1e2fuwe3uhq8zd9nkkk876nawrwdulgv47mkgww";
+
+    type TestTokens = Tokens<'static, Extension, Empty, Empty, Empty>;
+
+    fn setup(deps: &mut DepsMut) -> Rc<RefCell<Redeemable<'static>>> {
+        Ownable::default()
+            .owner
+            .save(deps.storage, &Addr::unchecked(CREATOR))
+            .unwrap();
+        let redeemable = Rc::new(RefCell::new(Redeemable::default()));
+        redeemable
+            .borrow_mut()
+            .instantiate(
+                deps,
+                &mock_env(),
+                &mock_info(CREATOR, &[]),
+                InstantiateMsg {
+                    locked_items: Set::new(),
+                },
+            )
+            .unwrap();
+        redeemable
+    }
+
+    fn is_redeemed(redeemable: &Redeemable, deps: Deps, token_id: &str) -> bool {
+        redeemable
+            .query(
+                &deps,
+                mock_env(),
+                QueryMsg::IsRedeemed(token_id.to_string()),
+            )
+            .unwrap()
+            == QueryResp::IsRedeemed(true)
+    }
+
+    #[test]
+    fn redeemers() {
+        let mut deps = mock_dependencies();
+        let redeemable = setup(&mut deps.as_mut());
+        let mut redeemable = redeemable.borrow_mut();
+
+        let err = redeemable
+            .redeem_item(
+                &mut deps.as_mut(),
+                mock_env(),
+                mock_info(REDEEMER, &[]),
+                "1".to_string(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized));
+        assert!(!is_redeemed(&redeemable, deps.as_ref(), "1"));
+
+        Roles::default()
+            .grant_role(
+                &mut deps.as_mut(),
+                Role::Redeemer,
+                &Addr::unchecked(REDEEMER),
+            )
+            .unwrap();
+        redeemable
+            .redeem_item(
+                &mut deps.as_mut(),
+                mock_env(),
+                mock_info(REDEEMER, &[]),
+                "1".to_string(),
+            )
+            .unwrap();
+        assert!(is_redeemed(&redeemable, deps.as_ref(), "1"));
+
+        // the owner redeems without the role
+        redeemable
+            .redeem_item(
+                &mut deps.as_mut(),
+                mock_env(),
+                mock_info(CREATOR, &[]),
+                "2".to_string(),
+            )
+            .unwrap();
+        assert!(is_redeemed(&redeemable, deps.as_ref(), "2"));
+    }
+
+    #[test]
+    fn burning_forgets_redemptions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let redeemable = setup(&mut deps.as_mut());
+        let mut tokens = TestTokens::default();
+        tokens
+            .instantiate(
+                &mut deps.as_mut(),
+                &env,
+                &mock_info(CREATOR, &[]),
+                cw721_base::InstantiateMsg {
+                    name: "burnt".to_string(),
+                    symbol: "BRNT".to_string(),
+                    minter: CREATOR.to_string(),
+                },
+            )
+            .unwrap();
+        tokens.add_hook(RedemptionHook::new(redeemable.clone()));
+
+        let mint = || {
+            ExecuteMsg::Base(cw721_base::ExecuteMsg::Mint(MintMsg {
+                token_id: "1".to_string(),
+                owner: USER.to_string(),
+                token_uri: None,
+                extension: None,
+            }))
+        };
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                mint(),
+            )
+            .unwrap();
+        redeemable
+            .borrow_mut()
+            .redeem_item(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                "1".to_string(),
+            )
+            .unwrap();
+        assert!(is_redeemed(&redeemable.borrow(), deps.as_ref(), "1"));
+
+        // a token minted again under a burnt id starts unredeemed
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::Base(cw721_base::ExecuteMsg::Burn {
+                    token_id: "1".to_string(),
+                }),
+            )
+            .unwrap();
+        assert!(!is_redeemed(&redeemable.borrow(), deps.as_ref(), "1"));
+        tokens
+            .execute(&mut deps.as_mut(), env, mock_info(CREATOR, &[]), mint())
+            .unwrap();
+        assert!(!is_redeemed(&redeemable.borrow(), deps.as_ref(), "1"));
+    }
+}
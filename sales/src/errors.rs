@@ -9,6 +9,9 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized,
 
+    #[error("No payout address to receive sale funds")]
+    NoPayoutError,
+
     #[error("Ongoing Primary Sale")]
     OngoingPrimarySaleError,
 
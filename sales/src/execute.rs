@@ -4,10 +4,12 @@ use burnt_glue::response::Response;
 use cosmwasm_std::{
     BankMsg, Coin, CosmosMsg, CustomMsg, DepsMut, Env, MessageInfo, Timestamp, Uint64,
 };
-use cw721_base::{state::TokenInfo, MintMsg};
+use cw721_base::state::TokenInfo;
 use cw_storage_plus::Item;
+use ownable::roles::Role;
 use sellable::Sellable;
 use serde::{de::DeserializeOwned, Serialize};
+use token::msg::MintMsg;
 
 use crate::{errors::ContractError, msg::CreatePrimarySale, PrimarySale, Sales};
 
@@ -47,18 +49,8 @@ where
             ));
         }
 
-        // validate contract owner
-        if info.sender
-            != self
-                .sellable
-                .borrow()
-                .ownable
-                .borrow()
-                .owner
-                .load(deps.storage)?
-        {
-            return Err(ContractError::Unauthorized);
-        }
+        // validate sale admin
+        self.check_sale_admin(deps, info)?;
 
         // make sure no active primary sale
         let start_time = Timestamp::from_seconds(msg.start_time.u64());
@@ -83,17 +75,7 @@ where
         env: Env,
         info: MessageInfo,
     ) -> Result<Response, ContractError> {
-        if info.sender
-            != self
-                .sellable
-                .borrow()
-                .ownable
-                .borrow()
-                .owner
-                .load(deps.storage)?
-        {
-            return Err(ContractError::Unauthorized);
-        }
+        self.check_sale_admin(deps, &info)?;
 
         let mut primary_sales = self.primary_sales.load(deps.storage)?;
 
@@ -107,6 +89,15 @@ where
         Err(ContractError::NoOngoingPrimarySaleError)
     }
 
+    fn check_sale_admin(&self, deps: &DepsMut, info: &MessageInfo) -> Result<(), ContractError> {
+        let sellable = self.sellable.borrow();
+        let roles = sellable.roles.borrow();
+        if !roles.is_authorized(&deps.as_ref(), Role::SaleAdmin, &info.sender)? {
+            return Err(ContractError::Unauthorized);
+        }
+        Ok(())
+    }
+
     pub fn buy_item(
         &mut self,
         env: Env,
@@ -138,20 +129,21 @@ where
                     }
                 }
                 // mint the item
-                let mut response = self.mint(deps, env, &info, mint_msg).unwrap();
+                let mut response = self.mint(deps, env, &info, mint_msg)?;
                 sale.tokens_minted = sale.tokens_minted.checked_add(Uint64::from(1_u8)).unwrap();
 
                 if sale.tokens_minted.eq(&sale.total_supply) {
                     sale.disabled = true;
                 }
-                // send funds to creator
+                // send funds to the payout address, which stays put when
+                // ownership moves to the contract itself
                 let ownable = &self.sellable.borrow().ownable;
+                let payout = ownable
+                    .borrow()
+                    .get_payout(&deps.as_ref())?
+                    .ok_or(ContractError::NoPayoutError)?;
                 let message = BankMsg::Send {
-                    to_address: ownable
-                        .borrow()
-                        .get_owner(&deps.as_ref())
-                        .unwrap()
-                        .to_string(),
+                    to_address: payout.to_string(),
                     amount: vec![Coin::new(
                         sale.price[0].amount.u128(),
                         sale.price[0].denom.clone(),
@@ -194,32 +186,36 @@ where
             token_uri: msg.token_uri,
             extension: msg.extension,
         };
-        {
-            self.sellable
-                .borrow()
-                .tokens
-                .borrow_mut()
+        let token_id = {
+            let sellable = self.sellable.borrow();
+            let tokens = sellable.tokens.borrow();
+            let token_id = match msg.token_id {
+                Some(token_id) => token_id,
+                None => tokens.next_token_id(deps)?,
+            };
+            tokens
                 .contract
                 .tokens
-                .update(deps.storage, &msg.token_id, |old| match old {
+                .update(deps.storage, &token_id, |old| match old {
                     Some(_) => Err(ContractError::TokenModuleError(
                         cw721_base::ContractError::Claimed {},
                     )),
                     None => Ok(token),
                 })?;
-        }
-        self.sellable
-            .borrow()
-            .tokens
-            .borrow_mut()
-            .contract
-            .increment_tokens(deps.storage)?;
+            if msg.soulbound {
+                tokens
+                    .soulbound_tokens
+                    .save(deps.storage, &token_id, &true)?;
+            }
+            tokens.contract.increment_tokens(deps.storage)?;
+            token_id
+        };
 
         Ok(Response::new()
             .add_attribute("action", "mint")
             .add_attribute("minter", &info.sender)
             .add_attribute("owner", msg.owner)
-            .add_attribute("token_id", msg.token_id))
+            .add_attribute("token_id", token_id))
     }
 }
 
@@ -1,7 +1,7 @@
 use cosmwasm_std::{Coin, Timestamp, Uint64};
-use cw721_base::MintMsg;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use token::msg::MintMsg;
 
 use crate::PrimarySale;
 
@@ -45,6 +45,8 @@ impl From<CreatePrimarySale> for PrimarySale {
 pub enum ExecuteMsg<T> {
     PrimarySale(CreatePrimarySale),
     HaltSale {},
+    // BuyItem mints a token to the buyer, the token id is generated if left
+    // out
     BuyItem(MintMsg<T>),
 }
 
@@ -3,12 +3,17 @@ mod tests {
     use std::{cell::RefCell, rc::Rc};
 
     use allowable::Allowable;
+    use burnt_glue::manager::Manager;
     use burnt_glue::module::Module;
     use cosmwasm_std::{
+        coins,
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr, Coin, Empty, Timestamp, Uint128, Uint64,
+        Addr, Binary, Coin, Deps, DepsMut, Empty, Env, MessageInfo, StdError, StdResult, Timestamp,
+        Uint128, Uint64,
     };
+    use cw_multi_test::{BasicAppBuilder, ContractWrapper, Executor};
     use cw_storage_plus::{Item, Map};
+    use ownable::roles::{Roles, ROLE_MEMBERS};
     use ownable::Ownable;
     use sellable::Sellable;
     use token::Tokens;
@@ -18,7 +23,7 @@ mod tests {
         Sales,
     };
     use cw721_base::msg::InstantiateMsg as cw721_baseInstantiateMsg;
-    use serde_json::{from_str, json};
+    use serde_json::{from_str, json, Value};
 
     const CREATOR: &str = "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z";
     const USER: &str = "burnt188rjfzzrdxlus60zgnrvs4rg0l73hct3mlvdpe";
@@ -253,5 +258,263 @@ mod tests {
             }
             _ => panic!(),
         }
+
+        // leaving the token id out mints the next generated id, skipping "1"
+        // which was bought by id
+        let json_exec_msg = json!({
+            "buy_item": {
+                    "owner": CREATOR,
+                    "token_uri": "url",
+                    "extension": {}
+                }
+        })
+        .to_string();
+        let execute_msg: ExecuteMsg<Empty> = from_str(&json_exec_msg).unwrap();
+        let res = sales
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                buyer_info.borrow_mut().clone(),
+                execute_msg,
+            )
+            .expect("item bought");
+        let token_id = res
+            .response
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "token_id")
+            .unwrap();
+        assert_eq!(token_id.value, "2");
+    }
+
+    const CO_OWNER: &str = "burnt1e2fuwe3uhq8zd9nkkk876nawrwdulgv4cxkq74";
+    const SALE_ADMIN: &str = "burnt1ptzwy3yqkyxvrnakylp9le2m3rc0fhs8yv3g4y";
+
+    // collection composes ownable, roles and sales the way a contract would,
+    // the modules only hold storage keys so they are built for every message
+    fn collection() -> Manager {
+        let ownable = Rc::new(RefCell::new(Ownable::default()));
+        let roles = Rc::new(RefCell::new(Roles::new(ROLE_MEMBERS, ownable.clone())));
+        ownable.borrow_mut().set_roles(&roles);
+        let sellable = Sellable::<Empty, Empty, Empty, Empty>::new(
+            Rc::new(RefCell::new(Tokens::default())),
+            ownable.clone(),
+            Map::new("listed_tokens"),
+        );
+        let sales: Sales<Empty, Empty, Empty, Empty> =
+            Sales::new(Rc::new(RefCell::new(sellable)), Item::new("primary_sales"));
+
+        let mut manager = Manager::new();
+        manager.register("ownable".to_string(), ownable).unwrap();
+        manager.register("roles".to_string(), roles).unwrap();
+        manager
+            .register("sales".to_string(), Rc::new(RefCell::new(sales)))
+            .unwrap();
+        manager
+    }
+
+    fn collection_instantiate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: Value,
+    ) -> StdResult<cosmwasm_std::Response<Binary>> {
+        collection()
+            .instantiate(deps, env, info, &msg.to_string())
+            .map_err(StdError::generic_err)
+    }
+
+    fn collection_execute(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: Value,
+    ) -> StdResult<cosmwasm_std::Response<Binary>> {
+        collection()
+            .execute(&mut deps, env, info, &msg.to_string())
+            .map_err(StdError::generic_err)
+    }
+
+    fn collection_query(deps: Deps, env: Env, msg: Value) -> StdResult<Binary> {
+        collection().query(&deps, env, &msg.to_string())
+    }
+
+    fn primary_sale(start_time: u64) -> Value {
+        json!({
+            "sales": {
+                "primary_sale": {
+                    "total_supply": "0",
+                    "start_time": start_time.to_string(),
+                    "end_time": (start_time + 1000).to_string(),
+                    "price": [{
+                        "denom": "USDC",
+                        "amount": "10"
+                    }]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn primary_sale_under_co_owners() {
+        let mut app = BasicAppBuilder::<Binary, Empty>::new_custom().build(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked(USER), coins(20, "USDC"))
+                .unwrap();
+        });
+        let code_id = app.store_code(Box::new(ContractWrapper::new(
+            collection_execute,
+            collection_instantiate,
+            collection_query,
+        )));
+        let collection = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked(CREATOR),
+                &json!({
+                    "ownable": {
+                        "roles": [{ "role": "sale_admin", "address": SALE_ADMIN }]
+                    },
+                    "sales": { "sale": null }
+                }),
+                &[],
+                "collection",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked(CREATOR),
+            collection.clone(),
+            &json!({
+                "ownable": {
+                    "set_co_owners": { "owners": [CREATOR, CO_OWNER], "threshold": 2 }
+                }
+            }),
+            &[],
+        )
+        .unwrap();
+
+        // neither the previous owner nor a sale admin can add a sale alone
+        let sale = primary_sale(app.block_info().time.seconds());
+        app.execute_contract(Addr::unchecked(CREATOR), collection.clone(), &sale, &[])
+            .expect_err("sale added by previous owner");
+        app.execute_contract(Addr::unchecked(SALE_ADMIN), collection.clone(), &sale, &[])
+            .expect_err("sale added by sale admin");
+
+        let msg = Binary::from(serde_json::to_vec(&sale).unwrap());
+        app.execute_contract(
+            Addr::unchecked(CREATOR),
+            collection.clone(),
+            &json!({ "ownable": { "propose_action": { "msg": msg } } }),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            Addr::unchecked(CO_OWNER),
+            collection.clone(),
+            &json!({ "ownable": { "approve_action": { "id": 1 } } }),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            Addr::unchecked(CO_OWNER),
+            collection.clone(),
+            &json!({ "ownable": { "execute_action": { "id": 1 } } }),
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked(USER),
+            collection.clone(),
+            &json!({
+                "sales": {
+                    "buy_item": {
+                        "token_id": "1",
+                        "owner": USER,
+                        "token_uri": "url",
+                        "extension": {}
+                    }
+                }
+            }),
+            &coins(20, "USDC"),
+        )
+        .unwrap();
+
+        // the price went to the previous owner and the rest back to the buyer,
+        // nothing is left in the contract
+        let balance = |addr: &Addr| {
+            app.wrap()
+                .query_balance(addr, "USDC")
+                .unwrap()
+                .amount
+                .u128()
+        };
+        assert_eq!(balance(&Addr::unchecked(CREATOR)), 10);
+        assert_eq!(balance(&Addr::unchecked(USER)), 10);
+        assert_eq!(balance(&collection), 0);
+    }
+
+    #[test]
+    fn primary_sale_after_renouncing() {
+        let mut app = BasicAppBuilder::<Binary, Empty>::new_custom().build(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked(USER), coins(20, "USDC"))
+                .unwrap();
+        });
+        let code_id = app.store_code(Box::new(ContractWrapper::new(
+            collection_execute,
+            collection_instantiate,
+            collection_query,
+        )));
+        let collection = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked(CREATOR),
+                &json!({ "ownable": {}, "sales": { "sale": null } }),
+                &[],
+                "collection",
+                None,
+            )
+            .unwrap();
+
+        let sale = primary_sale(app.block_info().time.seconds());
+        app.execute_contract(Addr::unchecked(CREATOR), collection.clone(), &sale, &[])
+            .unwrap();
+        app.execute_contract(
+            Addr::unchecked(CREATOR),
+            collection.clone(),
+            &json!({ "ownable": { "renounce_ownership": {} } }),
+            &[],
+        )
+        .unwrap();
+
+        // the running sale keeps selling and paying the previous owner
+        app.execute_contract(
+            Addr::unchecked(USER),
+            collection.clone(),
+            &json!({
+                "sales": {
+                    "buy_item": {
+                        "token_id": "1",
+                        "owner": USER,
+                        "token_uri": "url",
+                        "extension": {}
+                    }
+                }
+            }),
+            &coins(10, "USDC"),
+        )
+        .unwrap();
+        let balance = app
+            .wrap()
+            .query_balance(CREATOR, "USDC")
+            .unwrap()
+            .amount
+            .u128();
+        assert_eq!(balance, 10);
     }
 }
@@ -51,6 +51,9 @@ pub enum ContractError {
     #[error("Token ID not found")]
     TokenIDNotFoundError,
 
+    #[error("Token is soulbound")]
+    TokenSoulbound,
+
     #[error("{0}")]
     BaseError(#[from] cw721_base::ContractError),
 }
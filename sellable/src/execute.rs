@@ -1,11 +1,13 @@
 use std::{cell::RefCell, ops::Sub, rc::Rc};
 
-use crate::{errors::ContractError, RSellable, Sellable};
+use crate::{errors::ContractError, hooks::ListingHook, RSellable, Sellable};
 use allowable::Allowable;
 use burnt_glue::response::Response;
 use cosmwasm_std::{BankMsg, Coin, CustomMsg, Deps, DepsMut, Env, MessageInfo, Order, Uint128};
 use cw_storage_plus::Map;
+use ownable::roles::{Role, Roles, ROLE_MEMBERS};
 use ownable::Ownable;
+use redeemable::hooks::RedemptionHook;
 use redeemable::Redeemable;
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::json;
@@ -23,8 +25,15 @@ where
         ownable_module: Rc<RefCell<Ownable<'a>>>,
         listed_tokens: Map<'a, &'a str, Coin>,
     ) -> Self {
+        tokens_module
+            .borrow_mut()
+            .add_hook(ListingHook::new(listed_tokens.clone()));
         Self {
             tokens: tokens_module,
+            roles: Rc::new(RefCell::new(Roles::new(
+                ROLE_MEMBERS,
+                ownable_module.clone(),
+            ))),
             ownable: ownable_module,
             listed_tokens,
         }
@@ -37,8 +46,8 @@ where
         info: MessageInfo,
         listings: schemars::Map<String, Coin>,
     ) -> Result<Response, ContractError> {
-        let ownable = &self.ownable.borrow();
-        check_ownable(&deps.as_ref(), &env, &info, ownable)?;
+        let roles = &self.roles.borrow();
+        check_role(&deps.as_ref(), &env, &info, roles, Role::Lister)?;
 
         for (token_id, price) in listings {
             if price.amount > Uint128::new(0) {
@@ -56,6 +65,7 @@ where
                     .tokens
                     .may_load(deps.storage, &token_id)
                 {
+                    check_transferable(&deps.as_ref(), &self.tokens.borrow(), &token_id)?;
                     self.listed_tokens
                         .save(deps.storage, token_id.as_str(), &price)?;
                 } else {
@@ -110,6 +120,7 @@ where
                     if fund.denom.ne(&price.denom) {
                         Err(ContractError::WrongFundError)
                     } else if fund.amount.ge(&price.amount) {
+                        check_transferable(&deps.as_ref(), &self.tokens.borrow(), &token_id)?;
                         let token_metadata = self
                             .tokens
                             .borrow()
@@ -191,8 +202,17 @@ where
         listed_tokens: Map<'a, &'a str, Coin>,
         redeemable_module: Rc<RefCell<Redeemable<'a>>>,
     ) -> Self {
+        {
+            let mut tokens = token_module.borrow_mut();
+            tokens.add_hook(ListingHook::new(listed_tokens.clone()));
+            tokens.add_hook(RedemptionHook::new(redeemable_module.clone()));
+        }
         Self {
             tokens: token_module,
+            roles: Rc::new(RefCell::new(Roles::new(
+                ROLE_MEMBERS,
+                ownable_module.clone(),
+            ))),
             ownable: ownable_module,
             listed_tokens,
             redeemable: redeemable_module,
@@ -206,10 +226,10 @@ where
         info: MessageInfo,
         listings: schemars::Map<String, Coin>,
     ) -> Result<Response, ContractError> {
-        let ownable = &self.ownable.borrow();
+        let roles = &self.roles.borrow();
         let redeemable = &self.redeemable.borrow();
 
-        check_ownable(&deps.as_ref(), &env, &info, ownable)?;
+        check_role(&deps.as_ref(), &env, &info, roles, Role::Lister)?;
         for (token_id, price) in &listings {
             if self.listed_tokens.may_load(deps.storage, &token_id).is_ok() {
                 return Err(ContractError::TokenAlreadyListed);
@@ -224,6 +244,7 @@ where
                     .is_some()
                 {
                     check_redeemable(&deps.as_ref(), &env, &info, token_id, redeemable)?;
+                    check_transferable(&deps.as_ref(), &self.tokens.borrow(), token_id)?;
                     self.listed_tokens
                         .save(deps.storage, token_id.as_str(), price)?;
                 } else {
@@ -281,6 +302,7 @@ where
                     } else if fund.amount.ge(&price.amount) {
                         let redeemable = &self.redeemable.borrow();
                         check_redeemable(&deps.as_ref(), env, &info, &token_id, redeemable)?;
+                        check_transferable(&deps.as_ref(), &self.tokens.borrow(), &token_id)?;
                         let token_metadata = self
                             .tokens
                             .borrow()
@@ -351,13 +373,14 @@ where
     }
 }
 
-fn check_ownable(
+fn check_role(
     deps: &Deps,
     _env: &Env,
     info: &MessageInfo,
-    ownable: &Ownable,
+    roles: &Roles,
+    role: Role,
 ) -> Result<(), ContractError> {
-    if !ownable.is_owner(deps, &info.sender)? {
+    if !roles.is_authorized(deps, role, &info.sender)? {
         return Err(ContractError::Unauthorized);
     }
     Ok(())
@@ -377,3 +400,20 @@ fn check_redeemable(
     }
     Ok(())
 }
+
+fn check_transferable<T, C, E, Q>(
+    deps: &Deps,
+    tokens: &Tokens<T, C, E, Q>,
+    token_id: &str,
+) -> Result<(), ContractError>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    Q: CustomMsg,
+    E: CustomMsg,
+{
+    // soulbound tokens can't change hands, so they can't be sold either
+    if !tokens.is_transferable(deps, token_id)? {
+        return Err(ContractError::TokenSoulbound);
+    }
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use cosmwasm_std::{Coin, DepsMut, StdResult};
+use cw_storage_plus::Map;
+use token::hooks::{TokenEvent, TokenHook};
+
+// ListingHook delists a token as soon as it leaves its owner, so a buyer never
+// pays the new owner at a price they didn't set
+pub struct ListingHook<'a> {
+    pub listed_tokens: Map<'a, &'a str, Coin>,
+}
+
+impl<'a> ListingHook<'a> {
+    pub fn new(listed_tokens: Map<'a, &'a str, Coin>) -> Self {
+        Self { listed_tokens }
+    }
+}
+
+impl TokenHook for ListingHook<'_> {
+    fn on_token_event(&self, deps: &mut DepsMut, event: &TokenEvent) -> StdResult<()> {
+        self.listed_tokens.remove(deps.storage, event.token_id());
+        Ok(())
+    }
+}
@@ -1,11 +1,13 @@
 pub mod errors;
 pub mod execute;
+pub mod hooks;
 pub mod msg;
 pub mod query;
 pub mod state;
 mod test;
 
 use cw_storage_plus::Map;
+use hooks::ListingHook;
 use state::LISTED_TOKENS;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -14,7 +16,9 @@ use allowable::Allowable;
 use cosmwasm_std::{Coin, CustomMsg, Deps, DepsMut, Env, MessageInfo};
 use errors::ContractError;
 use msg::{ExecuteMsg, InstantiateMsg, QueryMsg, QueryResp};
+use ownable::roles::Roles;
 use ownable::Ownable;
+use redeemable::hooks::RedemptionHook;
 use redeemable::Redeemable;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
@@ -32,6 +36,7 @@ where
 {
     pub tokens: Rc<RefCell<Tokens<'a, T, C, E, Q>>>,
     pub ownable: Rc<RefCell<Ownable<'a>>>,
+    pub roles: Rc<RefCell<Roles<'a>>>,
     pub listed_tokens: Map<'a, &'a str, Coin>,
 }
 
@@ -44,6 +49,7 @@ where
 {
     pub tokens: Rc<RefCell<Tokens<'a, T, C, E, Q>>>,
     pub ownable: Rc<RefCell<Ownable<'a>>>,
+    pub roles: Rc<RefCell<Roles<'a>>>,
     pub listed_tokens: Map<'a, &'a str, Coin>,
     pub redeemable: Rc<RefCell<Redeemable<'a>>>,
 }
@@ -56,9 +62,12 @@ where
     C: CustomMsg,
 {
     fn default() -> Self {
+        let mut tokens = Tokens::default();
+        tokens.add_hook(ListingHook::new(LISTED_TOKENS));
         Self {
-            tokens: Rc::new(RefCell::new(Tokens::default())),
+            tokens: Rc::new(RefCell::new(tokens)),
             ownable: Rc::new(RefCell::new(Ownable::default())),
+            roles: Rc::new(RefCell::new(Roles::default())),
             listed_tokens: LISTED_TOKENS,
         }
     }
@@ -72,11 +81,16 @@ where
     C: CustomMsg,
 {
     fn default() -> Self {
+        let redeemable = Rc::new(RefCell::new(Redeemable::default()));
+        let mut tokens = Tokens::default();
+        tokens.add_hook(ListingHook::new(LISTED_TOKENS));
+        tokens.add_hook(RedemptionHook::new(redeemable.clone()));
         Self {
-            tokens: Rc::new(RefCell::new(Tokens::default())),
+            tokens: Rc::new(RefCell::new(tokens)),
             ownable: Rc::new(RefCell::new(Ownable::default())),
+            roles: Rc::new(RefCell::new(Roles::default())),
             listed_tokens: LISTED_TOKENS,
-            redeemable: Rc::new(RefCell::new(Redeemable::default())),
+            redeemable,
         }
     }
 }
@@ -125,8 +139,8 @@ where
     fn query(&self, deps: &Deps, _env: Env, msg: QueryMsg) -> Result<Self::QueryResp, Self::Error> {
         match msg {
             QueryMsg::ListedTokens { start_after, limit } => {
-                let response = self.listed_tokens(deps, start_after, limit);
-                Ok(QueryResp::ListedTokens((response.unwrap()).tokens))
+                let response = self.listed_tokens(deps, start_after, limit)?;
+                Ok(QueryResp::ListedTokens(response.tokens))
             }
         }
     }
@@ -173,8 +187,8 @@ where
     fn query(&self, deps: &Deps, _env: Env, msg: QueryMsg) -> Result<Self::QueryResp, Self::Error> {
         match msg {
             QueryMsg::ListedTokens { start_after, limit } => {
-                let response = self.listed_tokens(deps, start_after, limit);
-                Ok(QueryResp::ListedTokens((response.unwrap()).tokens))
+                let response = self.listed_tokens(deps, start_after, limit)?;
+                Ok(QueryResp::ListedTokens(response.tokens))
             }
         }
     }
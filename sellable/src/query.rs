@@ -2,6 +2,7 @@ use std::borrow::Borrow;
 
 use cosmwasm_std::{Coin, CustomMsg, Deps, Order, StdResult};
 use cw721_base::state::TokenInfo;
+use cw721_base::Cw721Contract;
 use cw_storage_plus::Bound;
 use schemars::JsonSchema;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -30,15 +31,13 @@ where
         let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
         let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
 
-        let listed_tokens_sorted = listed_tokens
-            .range(deps.storage, start, None, Order::Descending)
-            .take(limit)
-            .map(|t| t.unwrap())
-            .map(|res| {
-                let token_info = contract.tokens.load(deps.storage, res.0.as_str()).unwrap();
-                (res.0, res.1, token_info)
-            })
-            .collect::<Vec<(String, Coin, TokenInfo<T>)>>();
+        let listed_tokens_sorted = listed_tokens_with_info(
+            deps,
+            contract,
+            listed_tokens
+                .range(deps.storage, start, None, Order::Descending)
+                .take(limit),
+        )?;
 
         Ok(ListedTokensResponse {
             tokens: listed_tokens_sorted,
@@ -65,15 +64,13 @@ where
         let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
         let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
 
-        let listed_tokens_sorted = listed_tokens
-            .range(deps.storage, start, None, Order::Descending)
-            .take(limit)
-            .map(|t| t.unwrap())
-            .map(|res| {
-                let token_info = contract.tokens.load(deps.storage, res.0.as_str()).unwrap();
-                (res.0, res.1, token_info)
-            })
-            .collect::<Vec<(String, Coin, TokenInfo<T>)>>();
+        let listed_tokens_sorted = listed_tokens_with_info(
+            deps,
+            contract,
+            listed_tokens
+                .range(deps.storage, start, None, Order::Descending)
+                .take(limit),
+        )?;
 
         Ok(ListedTokensResponse {
             tokens: listed_tokens_sorted,
@@ -81,6 +78,28 @@ where
     }
 }
 
+// listed_tokens_with_info pairs listings with their token info, skipping
+// listings left behind by tokens that no longer exist
+fn listed_tokens_with_info<T, C, E, Q>(
+    deps: &Deps,
+    contract: &Cw721Contract<T, C, E, Q>,
+    listings: impl Iterator<Item = StdResult<(String, Coin)>>,
+) -> StdResult<Vec<(String, Coin, TokenInfo<T>)>>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    Q: CustomMsg,
+    E: CustomMsg,
+{
+    let mut tokens = vec![];
+    for listing in listings {
+        let (token_id, price) = listing?;
+        if let Some(token_info) = contract.tokens.may_load(deps.storage, &token_id)? {
+            tokens.push((token_id, price, token_info));
+        }
+    }
+    Ok(tokens)
+}
+
 #[derive(Serialize, Clone, Deserialize, PartialEq, JsonSchema, Debug)]
 pub struct ListedTokensResponse<T> {
     /// Contains all token_ids in lexicographical ordering
@@ -1,13 +1,16 @@
 #[cfg(test)]
 mod tests {
+    use burnt_glue::module::Module;
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
         Addr, BankMsg, Coin, CosmosMsg, DepsMut, Empty, Env, MessageInfo, Uint128,
     };
     use cw721_base::{msg::InstantiateMsg as cw721_baseInstantiateMsg, MintMsg};
     use cw_storage_plus::Map;
+    use ownable::roles::Role;
     use ownable::Ownable;
     use std::{cell::RefCell, rc::Rc};
+    use token::msg::ExecuteMsg as TokensExecuteMsg;
     use token::Tokens;
 
     use crate::{errors::ContractError, Sellable};
@@ -311,4 +314,204 @@ mod tests {
         let result = sellable.listed_tokens(&deps.as_ref(), None, None).unwrap();
         assert_eq!(result.tokens.len(), 0);
     }
+
+    #[test]
+    fn lister_role_can_list() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut sellable = setup_sellable_module(&mut deps.as_mut(), &env, &info);
+
+        // Mint a token
+        sellable
+            .tokens
+            .borrow_mut()
+            .contract
+            .mint(
+                deps.as_mut(),
+                env.clone(),
+                info,
+                MintMsg::<Empty> {
+                    token_id: "1".to_string(),
+                    owner: CREATOR.to_string(),
+                    token_uri: Some("uri".to_string()),
+                    extension: Empty {},
+                },
+            )
+            .unwrap();
+
+        let listings = schemars::Map::from([(
+            "1".to_string(),
+            Coin {
+                amount: Uint128::new(10),
+                denom: "uturnt".to_string(),
+            },
+        )]);
+        let lister_info = mock_info(BUYER, &[]);
+        match sellable.try_list(
+            &mut deps.as_mut(),
+            env.clone(),
+            lister_info.clone(),
+            listings.clone(),
+        ) {
+            Err(ContractError::Unauthorized) => {}
+            _ => panic!(),
+        }
+
+        sellable
+            .roles
+            .borrow()
+            .grant_role(&mut deps.as_mut(), Role::Lister, &Addr::unchecked(BUYER))
+            .unwrap();
+        sellable
+            .try_list(&mut deps.as_mut(), env, lister_info, listings)
+            .unwrap();
+        let result = sellable.listed_tokens(&deps.as_ref(), None, None).unwrap();
+        assert_eq!(result.tokens.len(), 1);
+    }
+
+    #[test]
+    fn soulbound_tokens_cannot_be_sold() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut sellable = setup_sellable_module(&mut deps.as_mut(), &env, &info);
+
+        // Mint a token and list it while it is still transferable
+        sellable
+            .tokens
+            .borrow_mut()
+            .contract
+            .mint(
+                deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                MintMsg::<Empty> {
+                    token_id: "1".to_string(),
+                    owner: CREATOR.to_string(),
+                    token_uri: Some("uri".to_string()),
+                    extension: Empty {},
+                },
+            )
+            .unwrap();
+        let listings = schemars::Map::from([(
+            "1".to_string(),
+            Coin {
+                amount: Uint128::new(10),
+                denom: "uturnt".to_string(),
+            },
+        )]);
+        sellable
+            .try_list(
+                &mut deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                listings.clone(),
+            )
+            .unwrap();
+
+        // Once the collection is soulbound the listing can't be bought
+        sellable
+            .tokens
+            .borrow()
+            .set_soulbound_collection(&mut deps.as_mut(), true)
+            .unwrap();
+        let buyer_info = mock_info(
+            BUYER,
+            &[Coin {
+                amount: Uint128::new(10),
+                denom: "uturnt".to_string(),
+            }],
+        );
+        match sellable.try_buy_token(&mut deps.as_mut(), buyer_info, "1".to_string()) {
+            Err(ContractError::TokenSoulbound) => {}
+            _ => panic!(),
+        }
+
+        // nor can it be listed again
+        sellable
+            .try_delist(&mut deps.as_mut(), info.clone(), "1".to_string())
+            .unwrap();
+        match sellable.try_list(&mut deps.as_mut(), env, info, listings) {
+            Err(ContractError::TokenSoulbound) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn moved_tokens_are_delisted() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        let mut sellable = setup_sellable_module(&mut deps.as_mut(), &env, &info);
+
+        let mut listings = schemars::Map::new();
+        for token_id in ["1", "2", "3"] {
+            sellable
+                .tokens
+                .borrow_mut()
+                .contract
+                .mint(
+                    deps.as_mut(),
+                    env.clone(),
+                    info.clone(),
+                    MintMsg::<Empty> {
+                        token_id: token_id.to_string(),
+                        owner: CREATOR.to_string(),
+                        token_uri: None,
+                        extension: Empty {},
+                    },
+                )
+                .unwrap();
+            listings.insert(
+                token_id.to_string(),
+                Coin {
+                    amount: Uint128::new(10),
+                    denom: "uturnt".to_string(),
+                },
+            );
+        }
+        sellable
+            .try_list(&mut deps.as_mut(), env.clone(), info.clone(), listings)
+            .unwrap();
+
+        // transferring and burning through the tokens module delists
+        let transfer = TokensExecuteMsg::Base(cw721_base::ExecuteMsg::TransferNft {
+            recipient: BUYER.to_string(),
+            token_id: "1".to_string(),
+        });
+        let burn = TokensExecuteMsg::Base(cw721_base::ExecuteMsg::Burn {
+            token_id: "2".to_string(),
+        });
+        for msg in [transfer, burn] {
+            sellable
+                .tokens
+                .borrow_mut()
+                .execute(&mut deps.as_mut(), env.clone(), info.clone(), msg)
+                .unwrap();
+        }
+        let listed = sellable
+            .listed_tokens(&deps.as_ref(), None, None)
+            .unwrap()
+            .tokens;
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].0, "3");
+
+        // listings left behind by tokens that are gone are skipped
+        sellable
+            .tokens
+            .borrow()
+            .contract
+            .tokens
+            .remove(deps.as_mut().storage, "3")
+            .unwrap();
+        let listed = sellable
+            .listed_tokens(&deps.as_ref(), None, None)
+            .unwrap()
+            .tokens;
+        assert!(listed.is_empty());
+    }
 }
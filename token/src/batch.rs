@@ -0,0 +1,160 @@
+use cosmwasm_std::{CustomMsg, Deps, DepsMut, Env, Event, MessageInfo};
+use cw721_base::ContractError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashSet;
+
+use burnt_glue::response::Response;
+
+use crate::errors::TokenError;
+use crate::msg::{MintMsg, Transfer};
+use crate::{into_glue_response, Tokens};
+
+// Every item of a batch is checked before any of them is applied, and then
+// goes through the same path as the single message, so a batch either
+// succeeds or fails as a whole.
+impl<'a, T, C, E, Q> Tokens<'a, T, C, E, Q>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    Q: CustomMsg,
+    E: CustomMsg,
+    C: CustomMsg,
+{
+    pub fn batch_mint(
+        &self,
+        deps: &mut DepsMut,
+        env: Env,
+        info: MessageInfo,
+        mints: Vec<MintMsg<T>>,
+    ) -> Result<Response, TokenError> {
+        self.check_batch_mint(&deps.as_ref(), &info, &mints)?;
+        let mut resps = vec![];
+        for msg in mints {
+            resps.push(self.mint(deps, env.clone(), info.clone(), msg)?);
+        }
+        batch_response("batch_mint", resps)
+    }
+
+    pub fn batch_transfer(
+        &self,
+        deps: &mut DepsMut,
+        env: Env,
+        info: MessageInfo,
+        transfers: Vec<Transfer>,
+    ) -> Result<Response, TokenError> {
+        for transfer in &transfers {
+            deps.api.addr_validate(&transfer.recipient)?;
+            if !self.is_transferable(&deps.as_ref(), &transfer.token_id)? {
+                return Err(TokenError::Soulbound {});
+            }
+        }
+        let token_ids = transfers.iter().map(|t| t.token_id.as_str());
+        self.check_batch_send(&deps.as_ref(), &env, &info, token_ids)?;
+        let mut resps = vec![];
+        for transfer in transfers {
+            let msg = cw721_base::ExecuteMsg::TransferNft {
+                recipient: transfer.recipient,
+                token_id: transfer.token_id,
+            };
+            resps.push(self.execute_base(deps, env.clone(), info.clone(), msg)?);
+        }
+        batch_response("batch_transfer", resps)
+    }
+
+    pub fn batch_burn(
+        &self,
+        deps: &mut DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_ids: Vec<String>,
+    ) -> Result<Response, TokenError> {
+        let ids = token_ids.iter().map(String::as_str);
+        self.check_batch_send(&deps.as_ref(), &env, &info, ids)?;
+        let mut resps = vec![];
+        for token_id in token_ids {
+            let msg = cw721_base::ExecuteMsg::Burn { token_id };
+            resps.push(self.execute_base(deps, env.clone(), info.clone(), msg)?);
+        }
+        batch_response("batch_burn", resps)
+    }
+
+    fn check_batch_mint(
+        &self,
+        deps: &Deps,
+        info: &MessageInfo,
+        mints: &[MintMsg<T>],
+    ) -> Result<(), TokenError> {
+        self.check_minter(deps, &info.sender)?;
+        let mut seen = HashSet::new();
+        for msg in mints {
+            deps.api.addr_validate(&msg.owner)?;
+            // generated ids skip the ids taken by the time they are minted, a
+            // given id the batch generated earlier fails its own mint and so
+            // the whole batch
+            if let Some(token_id) = &msg.token_id {
+                if !seen.insert(token_id.as_str())
+                    || self.contract.tokens.has(deps.storage, token_id)
+                {
+                    return Err(ContractError::Claimed {}.into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // check_batch_send makes sure the sender may move or burn every token,
+    // each token at most once
+    fn check_batch_send<'b>(
+        &self,
+        deps: &Deps,
+        env: &Env,
+        info: &MessageInfo,
+        token_ids: impl Iterator<Item = &'b str>,
+    ) -> Result<(), TokenError> {
+        let mut seen = HashSet::new();
+        for token_id in token_ids {
+            if !seen.insert(token_id) {
+                return Err(TokenError::DuplicateToken {
+                    token_id: token_id.to_string(),
+                });
+            }
+            let token = self.contract.tokens.load(deps.storage, token_id)?;
+            self.contract.check_can_send(*deps, env, info, &token)?;
+        }
+        Ok(())
+    }
+}
+
+// batch_response turns the attributes of every item into an event of its own
+// so each token can still be told apart
+fn batch_response<C>(
+    action: &str,
+    resps: Vec<cosmwasm_std::Response<C>>,
+) -> Result<Response, TokenError>
+where
+    C: CustomMsg,
+{
+    if resps.is_empty() {
+        return Err(TokenError::EmptyBatch {});
+    }
+    let mut batch = cosmwasm_std::Response::<C>::new()
+        .add_attribute("action", action)
+        .add_attribute("count", resps.len().to_string());
+    for resp in resps {
+        let event_type = resp
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "action")
+            .map(|attr| attr.value.clone())
+            .unwrap_or_else(|| action.to_string());
+        let attributes = resp
+            .attributes
+            .into_iter()
+            .filter(|attr| attr.key != "action");
+        batch = batch
+            .add_event(Event::new(event_type).add_attributes(attributes))
+            .add_events(resp.events)
+            .add_submessages(resp.messages);
+    }
+    Ok(into_glue_response(batch)?)
+}
@@ -0,0 +1,32 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TokenError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Base(#[from] cw721_base::ContractError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Token is soulbound")]
+    Soulbound {},
+
+    #[error("Token {token_id} is locked")]
+    TokenLocked { token_id: String },
+
+    #[error("Batch is empty")]
+    EmptyBatch {},
+
+    #[error("Token {token_id} appears more than once in the batch")]
+    DuplicateToken { token_id: String },
+
+    #[error("Token id pattern {template} has no {{id}} placeholder")]
+    InvalidTokenIdPattern { template: String },
+
+    #[error("Royalty of {basis_points} basis points exceeds 10000")]
+    InvalidRoyalty { basis_points: u16 },
+}
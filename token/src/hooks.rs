@@ -0,0 +1,101 @@
+use cosmwasm_std::{Addr, CustomMsg, DepsMut, StdResult};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::Tokens;
+
+// TokenEvent describes a token leaving its owner through the cw721 messages
+#[derive(Clone, Debug, PartialEq)]
+pub enum TokenEvent {
+    Transfer {
+        token_id: String,
+        from: Addr,
+        to: Addr,
+    },
+    Send {
+        token_id: String,
+        from: Addr,
+        contract: Addr,
+    },
+    Burn {
+        token_id: String,
+        owner: Addr,
+    },
+}
+
+impl TokenEvent {
+    pub fn token_id(&self) -> &str {
+        match self {
+            TokenEvent::Transfer { token_id, .. }
+            | TokenEvent::Send { token_id, .. }
+            | TokenEvent::Burn { token_id, .. } => token_id,
+        }
+    }
+}
+
+// TokenHook lets other modules react to transfers, sends and burns, an error
+// reverts the whole message
+pub trait TokenHook {
+    fn on_token_event(&self, deps: &mut DepsMut, event: &TokenEvent) -> StdResult<()>;
+}
+
+impl<'a, T, C, E, Q> Tokens<'a, T, C, E, Q>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    Q: CustomMsg,
+    E: CustomMsg,
+    C: CustomMsg,
+{
+    pub fn add_hook(&mut self, hook: impl TokenHook + 'a) {
+        self.hooks.push(Box::new(hook));
+    }
+
+    // token_event works out the event a cw721 message will cause, it must be
+    // called before the message is executed to know the previous owner
+    pub(crate) fn token_event(
+        &self,
+        deps: &DepsMut,
+        msg: &cw721_base::ExecuteMsg<T, E>,
+    ) -> StdResult<Option<TokenEvent>> {
+        use cw721_base::ExecuteMsg;
+
+        if self.hooks.is_empty() {
+            return Ok(None);
+        }
+        let token_id = match msg {
+            ExecuteMsg::TransferNft { token_id, .. }
+            | ExecuteMsg::SendNft { token_id, .. }
+            | ExecuteMsg::Burn { token_id } => token_id,
+            _ => return Ok(None),
+        };
+        let owner = match self.contract.tokens.may_load(deps.storage, token_id)? {
+            Some(token) => token.owner,
+            // cw721 will reject the message itself
+            None => return Ok(None),
+        };
+        let event = match msg {
+            ExecuteMsg::TransferNft { recipient, .. } => TokenEvent::Transfer {
+                token_id: token_id.clone(),
+                from: owner,
+                to: deps.api.addr_validate(recipient)?,
+            },
+            ExecuteMsg::SendNft { contract, .. } => TokenEvent::Send {
+                token_id: token_id.clone(),
+                from: owner,
+                contract: deps.api.addr_validate(contract)?,
+            },
+            _ => TokenEvent::Burn {
+                token_id: token_id.clone(),
+                owner,
+            },
+        };
+        Ok(Some(event))
+    }
+
+    pub(crate) fn run_hooks(&self, deps: &mut DepsMut, event: &TokenEvent) -> StdResult<()> {
+        for hook in &self.hooks {
+            hook.on_token_event(deps, event)?;
+        }
+        Ok(())
+    }
+}
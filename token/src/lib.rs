@@ -1,12 +1,38 @@
-use cosmwasm_std::{Binary, CustomMsg, Deps, DepsMut, Env, MessageInfo};
-use cw721_base::{ContractError, Cw721Contract, ExecuteMsg, InstantiateMsg, QueryMsg};
-use schemars::JsonSchema;
+mod batch;
+pub mod errors;
+pub mod hooks;
+pub mod msg;
+pub mod royalties;
+mod soulbound;
+mod test;
+pub mod token_ids;
+mod updates;
+
+use cosmwasm_std::{
+    Addr, Binary, CosmosMsg, CustomMsg, Deps, DepsMut, Env, MessageInfo, StdError, StdResult,
+    SubMsg,
+};
+use cw721::Cw721Query;
+use cw721_base::state::TokenInfo;
+use cw721_base::{Cw721Contract, InstantiateMsg};
+use cw_storage_plus::{Item, Map};
+use ownable::roles::{Role, Roles};
 use serde::de::DeserializeOwned;
-use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 use burnt_glue::module::Module;
 use burnt_glue::response::Response;
+use errors::TokenError;
+use hooks::TokenHook;
+use msg::{ExecuteMsg, MintMsg, QueryMsg, QueryResp, TokensExecuteMsg, TokensQueryMsg};
+use royalties::{CheckRoyaltiesResponse, Royalty, COLLECTION_ROYALTY, TOKEN_ROYALTIES};
+use token_ids::{TokenIdGenerator, TOKEN_ID_COUNTER, TOKEN_ID_GENERATOR};
+
+pub const SOULBOUND: Item<bool> = Item::new("soulbound");
+pub const SOULBOUND_TOKENS: Map<&str, bool> = Map::new("soulbound_tokens");
+pub const LOCKED_TOKENS: Map<&str, bool> = Map::new("locked_tokens");
 
 pub struct Tokens<'a, T, C, E, Q>
 where
@@ -15,6 +41,15 @@ where
     E: CustomMsg,
 {
     pub contract: Cw721Contract<'a, T, C, E, Q>,
+    pub collection_royalty: Item<'a, Royalty>,
+    pub token_royalties: Map<'a, &'a str, Royalty>,
+    pub soulbound: Item<'a, bool>,
+    pub soulbound_tokens: Map<'a, &'a str, bool>,
+    pub token_id_generator: Item<'a, TokenIdGenerator>,
+    pub token_id_counter: Item<'a, u64>,
+    pub locked_tokens: Map<'a, &'a str, bool>,
+    pub roles: Rc<RefCell<Roles<'a>>>,
+    pub hooks: Vec<Box<dyn TokenHook + 'a>>,
 }
 
 impl<'a, T, C, E, Q> Default for Tokens<'a, T, C, E, Q>
@@ -22,11 +57,10 @@ where
     T: Serialize + DeserializeOwned + Clone,
     Q: CustomMsg,
     E: CustomMsg,
+    C: CustomMsg,
 {
     fn default() -> Self {
-        Self {
-            contract: Cw721Contract::<T, C, E, Q>::default(),
-        }
+        Self::new(Cw721Contract::<T, C, E, Q>::default())
     }
 }
 
@@ -35,16 +69,230 @@ where
     T: Serialize + DeserializeOwned + Clone,
     Q: CustomMsg,
     E: CustomMsg,
+    C: CustomMsg,
 {
     pub fn new(contract: Cw721Contract<'a, T, C, E, Q>) -> Self {
-        Self { contract }
+        Self {
+            contract,
+            collection_royalty: COLLECTION_ROYALTY,
+            token_royalties: TOKEN_ROYALTIES,
+            soulbound: SOULBOUND,
+            soulbound_tokens: SOULBOUND_TOKENS,
+            token_id_generator: TOKEN_ID_GENERATOR,
+            token_id_counter: TOKEN_ID_COUNTER,
+            locked_tokens: LOCKED_TOKENS,
+            roles: Rc::new(RefCell::new(Roles::default())),
+            hooks: vec![],
+        }
+    }
+
+    // check_minter accepts the cw721 minter and holders of the minter role
+    pub fn check_minter(&self, deps: &Deps, sender: &Addr) -> Result<(), TokenError> {
+        if self.contract.minter.load(deps.storage)? == *sender {
+            return Ok(());
+        }
+        if !self
+            .roles
+            .borrow()
+            .is_authorized(deps, Role::Minter, sender)?
+        {
+            return Err(TokenError::Unauthorized {});
+        }
+        Ok(())
+    }
+
+    // execute_base runs a cw721 message with the soulbound checks and hooks
+    // of the Tokens module
+    pub fn execute_base(
+        &self,
+        deps: &mut DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: cw721_base::ExecuteMsg<T, E>,
+    ) -> Result<cosmwasm_std::Response<C>, TokenError> {
+        // cw721 only lets its own minter mint
+        if let cw721_base::ExecuteMsg::Mint(msg) = msg {
+            return self.mint(deps, env, info, msg.into());
+        }
+        self.check_transfer_allowed(&deps.as_ref(), &msg)?;
+        let event = self.token_event(deps, &msg)?;
+        let burnt = match &msg {
+            cw721_base::ExecuteMsg::Burn { token_id } => Some(token_id.clone()),
+            _ => None,
+        };
+        let resp = self.contract.execute(deps.branch(), env, info, msg)?;
+        if let Some(token_id) = burnt {
+            self.forget_token(deps, &token_id);
+        }
+        if let Some(event) = event {
+            self.run_hooks(deps, &event)?;
+        }
+        Ok(resp)
+    }
+
+    // forget_token drops what is kept about a burnt token, cw721 lets its id
+    // be minted again and the new token must start afresh
+    fn forget_token(&self, deps: &mut DepsMut, token_id: &str) {
+        self.locked_tokens.remove(deps.storage, token_id);
+        self.soulbound_tokens.remove(deps.storage, token_id);
+        self.token_royalties.remove(deps.storage, token_id);
+    }
+
+    pub fn mint(
+        &self,
+        deps: &mut DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        msg: MintMsg<T>,
+    ) -> Result<cosmwasm_std::Response<C>, TokenError> {
+        self.check_minter(&deps.as_ref(), &info.sender)?;
+        let token_id = match msg.token_id {
+            Some(token_id) => token_id,
+            None => self.next_token_id(deps)?,
+        };
+        // stored the way cw721 mints, which would only accept its own minter
+        let token = TokenInfo {
+            owner: deps.api.addr_validate(&msg.owner)?,
+            approvals: vec![],
+            token_uri: msg.token_uri,
+            extension: msg.extension,
+        };
+        self.contract
+            .tokens
+            .update(deps.storage, &token_id, |old| match old {
+                Some(_) => Err(cw721_base::ContractError::Claimed {}),
+                None => Ok(token),
+            })?;
+        self.contract.increment_tokens(deps.storage)?;
+        if msg.soulbound {
+            self.soulbound_tokens.save(deps.storage, &token_id, &true)?;
+        }
+        Ok(cosmwasm_std::Response::new()
+            .add_attribute("action", "mint")
+            .add_attribute("minter", info.sender)
+            .add_attribute("owner", msg.owner)
+            .add_attribute("token_id", token_id)
+            .add_attribute("soulbound", msg.soulbound.to_string()))
+    }
+
+    fn query_base(
+        &self,
+        deps: Deps,
+        env: Env,
+        msg: cw721_base::QueryMsg<Q>,
+    ) -> StdResult<QueryResp<T>> {
+        use cw721_base::QueryMsg;
+
+        let contract = &self.contract;
+        let resp = match msg {
+            QueryMsg::OwnerOf {
+                token_id,
+                include_expired,
+            } => QueryResp::OwnerOf(contract.owner_of(
+                deps,
+                env,
+                token_id,
+                include_expired.unwrap_or(false),
+            )?),
+            QueryMsg::Approval {
+                token_id,
+                spender,
+                include_expired,
+            } => QueryResp::Approval(contract.approval(
+                deps,
+                env,
+                token_id,
+                spender,
+                include_expired.unwrap_or(false),
+            )?),
+            QueryMsg::Approvals {
+                token_id,
+                include_expired,
+            } => QueryResp::Approvals(contract.approvals(
+                deps,
+                env,
+                token_id,
+                include_expired.unwrap_or(false),
+            )?),
+            QueryMsg::AllOperators {
+                owner,
+                include_expired,
+                start_after,
+                limit,
+            } => QueryResp::AllOperators(contract.operators(
+                deps,
+                env,
+                owner,
+                include_expired.unwrap_or(false),
+                start_after,
+                limit,
+            )?),
+            QueryMsg::NumTokens {} => QueryResp::NumTokens(contract.num_tokens(deps)?),
+            QueryMsg::ContractInfo {} => QueryResp::ContractInfo(contract.contract_info(deps)?),
+            QueryMsg::NftInfo { token_id } => {
+                QueryResp::NftInfo(contract.nft_info(deps, token_id)?)
+            }
+            QueryMsg::AllNftInfo {
+                token_id,
+                include_expired,
+            } => QueryResp::AllNftInfo(contract.all_nft_info(
+                deps,
+                env,
+                token_id,
+                include_expired.unwrap_or(false),
+            )?),
+            QueryMsg::Tokens {
+                owner,
+                start_after,
+                limit,
+            } => QueryResp::Tokens(contract.tokens(deps, owner, start_after, limit)?),
+            QueryMsg::AllTokens { start_after, limit } => {
+                QueryResp::AllTokens(contract.all_tokens(deps, start_after, limit)?)
+            }
+            QueryMsg::Minter {} => QueryResp::Minter(contract.minter(deps)?),
+            msg @ QueryMsg::Extension { .. } => QueryResp::Result(contract.query(deps, env, msg)?),
+        };
+        Ok(resp)
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum QueryResp {
-    Result(Binary),
+// into_glue_response carries the attributes, events, messages and data of a
+// cw721 response over into a glue response
+pub(crate) fn into_glue_response<C>(resp: cosmwasm_std::Response<C>) -> StdResult<Response>
+where
+    C: CustomMsg,
+{
+    let mut messages = vec![];
+    for sub in resp.messages {
+        messages.push(SubMsg {
+            id: sub.id,
+            msg: into_binary_msg(sub.msg)?,
+            gas_limit: sub.gas_limit,
+            reply_on: sub.reply_on,
+        });
+    }
+    let glue_resp = Response::new()
+        .add_attributes(resp.attributes)
+        .add_events(resp.events)
+        .add_submessages(messages);
+    Ok(match resp.data {
+        Some(data) => glue_resp.set_data(data),
+        None => glue_resp,
+    })
+}
+
+fn into_binary_msg<C>(msg: CosmosMsg<C>) -> StdResult<CosmosMsg<Binary>>
+where
+    C: CustomMsg,
+{
+    let to_err = |e: serde_json::Error| StdError::generic_err(e.to_string());
+    match msg {
+        CosmosMsg::Custom(custom) => Ok(CosmosMsg::Custom(Binary::from(
+            serde_json::to_vec(&custom).map_err(to_err)?,
+        ))),
+        // no other variant carries the custom type, so they encode the same
+        msg => serde_json::from_slice(&serde_json::to_vec(&msg).map_err(to_err)?).map_err(to_err),
+    }
 }
 
 impl<'a, T, C, E, Q> Module for Tokens<'a, T, C, E, Q>
@@ -57,8 +305,8 @@ where
     type InstantiateMsg = InstantiateMsg;
     type ExecuteMsg = ExecuteMsg<T, E>;
     type QueryMsg = QueryMsg<Q>;
-    type QueryResp = QueryResp;
-    type Error = ContractError;
+    type QueryResp = QueryResp<T>;
+    type Error = TokenError;
 
     fn instantiate(
         &mut self,
@@ -67,9 +315,10 @@ where
         info: &MessageInfo,
         msg: InstantiateMsg,
     ) -> Result<Response, Self::Error> {
-        self.contract
+        let resp = self
+            .contract
             .instantiate(deps.branch(), env.clone(), info.clone(), msg)?;
-        Ok(Response::new())
+        Ok(into_glue_response(resp)?)
     }
 
     fn execute(
@@ -79,8 +328,97 @@ where
         info: MessageInfo,
         msg: ExecuteMsg<T, E>,
     ) -> Result<Response, Self::Error> {
-        self.contract.execute(deps.branch(), env, info, msg)?;
-        Ok(Response::new())
+        match msg {
+            ExecuteMsg::Base(msg) => {
+                let resp = self.execute_base(deps, env, info, msg)?;
+                Ok(into_glue_response(resp)?)
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::Mint(msg)) => {
+                let resp = self.mint(deps, env, info, msg)?;
+                Ok(into_glue_response(resp)?)
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::BatchMint { mints }) => {
+                self.batch_mint(deps, env, info, mints)
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::BatchTransfer { transfers }) => {
+                self.batch_transfer(deps, env, info, transfers)
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::BatchBurn { token_ids }) => {
+                self.batch_burn(deps, env, info, token_ids)
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::UpdateTokenUri {
+                token_id,
+                token_uri,
+            }) => {
+                let event = self.update_token_uri(deps, &info.sender, &token_id, token_uri)?;
+                Ok(Response::new()
+                    .add_attribute("action", "update_token_uri")
+                    .add_event(event))
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::UpdateExtension {
+                token_id,
+                extension,
+            }) => {
+                let event = self.update_extension(deps, &info.sender, &token_id, extension)?;
+                Ok(Response::new()
+                    .add_attribute("action", "update_extension")
+                    .add_event(event))
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::LockToken { token_id }) => {
+                let event = self.lock_token(deps, &info.sender, &token_id)?;
+                Ok(Response::new()
+                    .add_attribute("action", "lock_token")
+                    .add_event(event))
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::SetTokenIdGenerator { generator }) => {
+                self.check_minter(&deps.as_ref(), &info.sender)?;
+                self.set_token_id_generator(deps, &generator)?;
+                Ok(Response::new().add_attribute("action", "set_token_id_generator"))
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::SetSoulbound { soulbound }) => {
+                self.check_minter(&deps.as_ref(), &info.sender)?;
+                self.set_soulbound_collection(deps, soulbound)?;
+                Ok(Response::new()
+                    .add_attribute("action", "set_soulbound")
+                    .add_attribute("soulbound", soulbound.to_string()))
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::SetCollectionRoyalty {
+                payment_address,
+                basis_points,
+            }) => {
+                self.check_minter(&deps.as_ref(), &info.sender)?;
+                self.set_collection_royalty(deps, &payment_address, basis_points)?;
+                Ok(Response::new()
+                    .add_attribute("action", "set_collection_royalty")
+                    .add_attribute("payment_address", payment_address)
+                    .add_attribute("basis_points", basis_points.to_string()))
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::RemoveCollectionRoyalty {}) => {
+                self.check_minter(&deps.as_ref(), &info.sender)?;
+                self.remove_collection_royalty(deps);
+                Ok(Response::new().add_attribute("action", "remove_collection_royalty"))
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::SetTokenRoyalty {
+                token_id,
+                payment_address,
+                basis_points,
+            }) => {
+                self.check_minter(&deps.as_ref(), &info.sender)?;
+                self.set_token_royalty(deps, &token_id, &payment_address, basis_points)?;
+                Ok(Response::new()
+                    .add_attribute("action", "set_token_royalty")
+                    .add_attribute("token_id", token_id)
+                    .add_attribute("payment_address", payment_address)
+                    .add_attribute("basis_points", basis_points.to_string()))
+            }
+            ExecuteMsg::Tokens(TokensExecuteMsg::RemoveTokenRoyalty { token_id }) => {
+                self.check_minter(&deps.as_ref(), &info.sender)?;
+                self.remove_token_royalty(deps, &token_id);
+                Ok(Response::new()
+                    .add_attribute("action", "remove_token_royalty")
+                    .add_attribute("token_id", token_id))
+            }
+        }
     }
 
     fn query(
@@ -89,7 +427,30 @@ where
         env: Env,
         msg: QueryMsg<Q>,
     ) -> Result<Self::QueryResp, Self::Error> {
-        let query_response = self.contract.query(*deps, env, msg)?;
-        Ok(QueryResp::Result(query_response))
+        match msg {
+            QueryMsg::Base(msg) => Ok(self.query_base(*deps, env, msg)?),
+            QueryMsg::Tokens(TokensQueryMsg::RoyaltyInfo {
+                token_id,
+                sale_price,
+            }) => {
+                let info = self.royalty_info(deps, &token_id, sale_price)?;
+                Ok(QueryResp::RoyaltyInfo(info))
+            }
+            QueryMsg::Tokens(TokensQueryMsg::IsLocked { token_id }) => {
+                Ok(QueryResp::IsLocked(self.is_locked(deps, &token_id)?))
+            }
+            QueryMsg::Tokens(TokensQueryMsg::NextTokenId {}) => {
+                Ok(QueryResp::NextTokenId(self.peek_token_id(deps)?))
+            }
+            QueryMsg::Tokens(TokensQueryMsg::IsTransferable { token_id }) => {
+                let transferable = self.is_transferable(deps, &token_id)?;
+                Ok(QueryResp::IsTransferable(transferable))
+            }
+            QueryMsg::Tokens(TokensQueryMsg::CheckRoyalties {}) => {
+                Ok(QueryResp::CheckRoyalties(CheckRoyaltiesResponse {
+                    royalty_payments: true,
+                }))
+            }
+        }
     }
 }
@@ -0,0 +1,171 @@
+use cosmwasm_std::{Addr, Binary, Uint128};
+use cw721::{
+    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ContractInfoResponse, NftInfoResponse,
+    NumTokensResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
+};
+use cw721_base::MinterResponse;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::royalties::{CheckRoyaltiesResponse, RoyaltiesInfoResponse};
+use crate::token_ids::TokenIdGenerator;
+
+// ExecuteMsg accepts the cw721 messages unchanged next to the messages added
+// by the Tokens module
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum ExecuteMsg<T, E> {
+    Base(cw721_base::ExecuteMsg<T, E>),
+    Tokens(TokensExecuteMsg<T>),
+}
+
+// MintMsg extends the cw721 mint message with the Tokens module options
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MintMsg<T> {
+    // token_id is generated by the module's TokenIdGenerator if left out
+    #[serde(default)]
+    pub token_id: Option<String>,
+    pub owner: String,
+    pub token_uri: Option<String>,
+    pub extension: T,
+    // soulbound tokens can never be transferred or approved
+    #[serde(default)]
+    pub soulbound: bool,
+}
+
+impl<T> From<cw721_base::MintMsg<T>> for MintMsg<T> {
+    fn from(msg: cw721_base::MintMsg<T>) -> Self {
+        MintMsg {
+            token_id: Some(msg.token_id),
+            owner: msg.owner,
+            token_uri: msg.token_uri,
+            extension: msg.extension,
+            soulbound: false,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Transfer {
+    pub recipient: String,
+    pub token_id: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TokensExecuteMsg<T> {
+    // Mint mints a token with the Tokens module options, minter only
+    Mint(MintMsg<T>),
+    // BatchMint mints every token or none of them, minter only
+    BatchMint {
+        mints: Vec<MintMsg<T>>,
+    },
+    // BatchTransfer transfers every token or none of them, each transfer is
+    // checked like a cw721 TransferNft
+    BatchTransfer {
+        transfers: Vec<Transfer>,
+    },
+    // BatchBurn burns every token or none of them, each burn is checked like a
+    // cw721 Burn
+    BatchBurn {
+        token_ids: Vec<String>,
+    },
+    // UpdateTokenUri changes the uri of an unlocked token, minter only
+    UpdateTokenUri {
+        token_id: String,
+        token_uri: Option<String>,
+    },
+    // UpdateExtension replaces the extension of an unlocked token, minter
+    // only
+    UpdateExtension {
+        token_id: String,
+        extension: T,
+    },
+    // LockToken permanently stops updates of the token, minter only
+    LockToken {
+        token_id: String,
+    },
+    // SetTokenIdGenerator sets how ids are generated for tokens minted
+    // without one, minter only
+    SetTokenIdGenerator {
+        generator: TokenIdGenerator,
+    },
+    // SetSoulbound makes every token of the collection non-transferable,
+    // minter only
+    SetSoulbound {
+        soulbound: bool,
+    },
+    // SetCollectionRoyalty sets the default royalty of every token, minter only
+    SetCollectionRoyalty {
+        payment_address: Addr,
+        basis_points: u16,
+    },
+    // RemoveCollectionRoyalty drops the default royalty, minter only
+    RemoveCollectionRoyalty {},
+    // SetTokenRoyalty overrides the royalty of a single token, minter only
+    SetTokenRoyalty {
+        token_id: String,
+        payment_address: Addr,
+        basis_points: u16,
+    },
+    // RemoveTokenRoyalty drops the royalty override of a token, minter only
+    RemoveTokenRoyalty {
+        token_id: String,
+    },
+}
+
+// QueryMsg accepts the cw721 queries unchanged next to the queries added by
+// the Tokens module
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum QueryMsg<Q> {
+    Base(cw721_base::QueryMsg<Q>),
+    Tokens(TokensQueryMsg),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TokensQueryMsg {
+    // RoyaltyInfo returns the royalty owed on a sale of the token (CW-2981)
+    RoyaltyInfo {
+        token_id: String,
+        sale_price: Uint128,
+    },
+    // CheckRoyalties signals that this contract implements CW-2981
+    CheckRoyalties {},
+    // IsLocked returns true if the token can no longer be updated
+    IsLocked {
+        token_id: String,
+    },
+    // NextTokenId returns the id the next token minted without one gets
+    NextTokenId {},
+    // IsTransferable returns false for soulbound tokens
+    IsTransferable {
+        token_id: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryResp<T> {
+    OwnerOf(OwnerOfResponse),
+    Approval(ApprovalResponse),
+    Approvals(ApprovalsResponse),
+    AllOperators(OperatorsResponse),
+    NumTokens(NumTokensResponse),
+    ContractInfo(ContractInfoResponse),
+    NftInfo(NftInfoResponse<T>),
+    AllNftInfo(AllNftInfoResponse<T>),
+    Tokens(TokensResponse),
+    AllTokens(TokensResponse),
+    Minter(MinterResponse),
+    // Result holds the raw response of extension queries
+    Result(Binary),
+    RoyaltyInfo(RoyaltiesInfoResponse),
+    CheckRoyalties(CheckRoyaltiesResponse),
+    IsTransferable(bool),
+    NextTokenId(String),
+    IsLocked(bool),
+}
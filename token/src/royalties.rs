@@ -0,0 +1,116 @@
+use cosmwasm_std::{Addr, CustomMsg, Deps, DepsMut, StdResult, Uint128};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::TokenError;
+use crate::Tokens;
+
+pub const COLLECTION_ROYALTY: Item<Royalty> = Item::new("collection_royalty");
+pub const TOKEN_ROYALTIES: Map<&str, Royalty> = Map::new("token_royalties");
+
+const MAX_BASIS_POINTS: u16 = 10_000;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Royalty {
+    pub payment_address: Addr,
+    // basis_points is the share of the sale price paid out, 100 being 1%
+    pub basis_points: u16,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct RoyaltiesInfoResponse {
+    pub address: String,
+    pub royalty_amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CheckRoyaltiesResponse {
+    pub royalty_payments: bool,
+}
+
+impl<'a, T, C, E, Q> Tokens<'a, T, C, E, Q>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    Q: CustomMsg,
+    E: CustomMsg,
+{
+    pub fn set_collection_royalty(
+        &self,
+        deps: &mut DepsMut,
+        payment_address: &Addr,
+        basis_points: u16,
+    ) -> Result<(), TokenError> {
+        let royalty = validate_royalty(&deps.as_ref(), payment_address, basis_points)?;
+        self.collection_royalty.save(deps.storage, &royalty)?;
+        Ok(())
+    }
+
+    pub fn remove_collection_royalty(&self, deps: &mut DepsMut) {
+        self.collection_royalty.remove(deps.storage)
+    }
+
+    pub fn set_token_royalty(
+        &self,
+        deps: &mut DepsMut,
+        token_id: &str,
+        payment_address: &Addr,
+        basis_points: u16,
+    ) -> Result<(), TokenError> {
+        // the token has to exist
+        self.contract.tokens.load(deps.storage, token_id)?;
+        let royalty = validate_royalty(&deps.as_ref(), payment_address, basis_points)?;
+        self.token_royalties
+            .save(deps.storage, token_id, &royalty)?;
+        Ok(())
+    }
+
+    pub fn remove_token_royalty(&self, deps: &mut DepsMut, token_id: &str) {
+        self.token_royalties.remove(deps.storage, token_id)
+    }
+
+    // get_royalty returns the royalty of the token, falling back to the
+    // collection royalty
+    pub fn get_royalty(&self, deps: &Deps, token_id: &str) -> StdResult<Option<Royalty>> {
+        match self.token_royalties.may_load(deps.storage, token_id)? {
+            Some(royalty) => Ok(Some(royalty)),
+            None => self.collection_royalty.may_load(deps.storage),
+        }
+    }
+
+    pub fn royalty_info(
+        &self,
+        deps: &Deps,
+        token_id: &str,
+        sale_price: Uint128,
+    ) -> StdResult<RoyaltiesInfoResponse> {
+        Ok(match self.get_royalty(deps, token_id)? {
+            Some(royalty) => RoyaltiesInfoResponse {
+                address: royalty.payment_address.to_string(),
+                royalty_amount: sale_price.multiply_ratio(royalty.basis_points, MAX_BASIS_POINTS),
+            },
+            None => RoyaltiesInfoResponse {
+                address: String::new(),
+                royalty_amount: Uint128::zero(),
+            },
+        })
+    }
+}
+
+fn validate_royalty(
+    deps: &Deps,
+    payment_address: &Addr,
+    basis_points: u16,
+) -> Result<Royalty, TokenError> {
+    if basis_points > MAX_BASIS_POINTS {
+        return Err(TokenError::InvalidRoyalty { basis_points });
+    }
+    Ok(Royalty {
+        payment_address: deps.api.addr_validate(payment_address.as_str())?,
+        basis_points,
+    })
+}
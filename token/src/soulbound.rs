@@ -0,0 +1,55 @@
+use cosmwasm_std::{CustomMsg, Deps, DepsMut, StdResult};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::TokenError;
+use crate::Tokens;
+
+impl<'a, T, C, E, Q> Tokens<'a, T, C, E, Q>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    Q: CustomMsg,
+    E: CustomMsg,
+{
+    pub fn is_soulbound_collection(&self, deps: &Deps) -> StdResult<bool> {
+        Ok(self.soulbound.may_load(deps.storage)?.unwrap_or(false))
+    }
+
+    pub fn set_soulbound_collection(&self, deps: &mut DepsMut, soulbound: bool) -> StdResult<()> {
+        self.soulbound.save(deps.storage, &soulbound)
+    }
+
+    // is_transferable returns false if the whole collection or the token
+    // itself is soulbound
+    pub fn is_transferable(&self, deps: &Deps, token_id: &str) -> StdResult<bool> {
+        Ok(!self.is_soulbound_collection(deps)?
+            && !self.soulbound_tokens.has(deps.storage, token_id))
+    }
+
+    // check_transfer_allowed rejects the cw721 messages that would let a
+    // soulbound token change hands
+    pub fn check_transfer_allowed(
+        &self,
+        deps: &Deps,
+        msg: &cw721_base::ExecuteMsg<T, E>,
+    ) -> Result<(), TokenError> {
+        use cw721_base::ExecuteMsg;
+
+        let token_id = match msg {
+            ExecuteMsg::TransferNft { token_id, .. }
+            | ExecuteMsg::SendNft { token_id, .. }
+            | ExecuteMsg::Approve { token_id, .. } => token_id,
+            ExecuteMsg::ApproveAll { .. } => {
+                if self.is_soulbound_collection(deps)? {
+                    return Err(TokenError::Soulbound {});
+                }
+                return Ok(());
+            }
+            _ => return Ok(()),
+        };
+        if !self.is_transferable(deps, token_id)? {
+            return Err(TokenError::Soulbound {});
+        }
+        Ok(())
+    }
+}
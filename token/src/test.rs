@@ -0,0 +1,1074 @@
+#[cfg(test)]
+mod tests {
+    use burnt_glue::module::Module;
+    use burnt_glue::response::Response;
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, Attribute, Binary, CosmosMsg, Deps, DepsMut, Empty, StdResult, Uint128, WasmMsg,
+    };
+    use cw721::{NftInfoResponse, NumTokensResponse, OwnerOfResponse, TokensResponse};
+    use cw721_base::{Extension, InstantiateMsg, MintMsg, MinterResponse};
+    use std::{cell::RefCell, rc::Rc};
+
+    use ownable::roles::{Role, Roles};
+
+    use crate::errors::TokenError;
+    use crate::hooks::{TokenEvent, TokenHook};
+    use crate::msg::{self, ExecuteMsg, QueryMsg, QueryResp, TokensExecuteMsg, TokensQueryMsg};
+    use crate::royalties::RoyaltiesInfoResponse;
+    use crate::token_ids::TokenIdGenerator;
+    use crate::Tokens;
+
+    const CREATOR: &str = "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z";
+    const USER: &str = "burnt188rjfzzrdxlus60zgnrvs4rg0l73hct3mlvdpe";
+    const RECEIVER: &str = "cosmos1rcvrcvrcvrcvrcvrcvrcvrcvrcvrcvrcvrcvrc";
+
+    type TestTokens = Tokens<'static, Extension, Empty, Empty, Empty>;
+
+    fn setup(deps: &mut DepsMut) -> TestTokens {
+        let mut tokens = TestTokens::default();
+        tokens
+            .instantiate(
+                deps,
+                &mock_env(),
+                &mock_info(CREATOR, &[]),
+                InstantiateMsg {
+                    name: "burnt".to_string(),
+                    symbol: "BRNT".to_string(),
+                    minter: CREATOR.to_string(),
+                },
+            )
+            .unwrap();
+        tokens
+    }
+
+    fn mint_msg(token_id: &str) -> ExecuteMsg<Extension, Empty> {
+        ExecuteMsg::Base(cw721_base::ExecuteMsg::Mint(MintMsg {
+            token_id: token_id.to_string(),
+            owner: USER.to_string(),
+            token_uri: None,
+            extension: None,
+        }))
+    }
+
+    fn attribute(attributes: &[Attribute], key: &str) -> String {
+        attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn forwards_mint_and_transfer_attributes() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let mut tokens = setup(&mut deps.as_mut());
+
+        let resp = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                mint_msg("1"),
+            )
+            .unwrap();
+        let attributes = &resp.response.attributes;
+        assert_eq!(attribute(attributes, "action"), "mint");
+        assert_eq!(attribute(attributes, "owner"), USER);
+        assert_eq!(attribute(attributes, "token_id"), "1");
+
+        let resp = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                mock_info(USER, &[]),
+                ExecuteMsg::Base(cw721_base::ExecuteMsg::TransferNft {
+                    recipient: CREATOR.to_string(),
+                    token_id: "1".to_string(),
+                }),
+            )
+            .unwrap();
+        let attributes = &resp.response.attributes;
+        assert_eq!(attribute(attributes, "action"), "transfer_nft");
+        assert_eq!(attribute(attributes, "sender"), USER);
+        assert_eq!(attribute(attributes, "recipient"), CREATOR);
+        assert_eq!(attribute(attributes, "token_id"), "1");
+    }
+
+    #[test]
+    fn forwards_send_nft_messages() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let mut tokens = setup(&mut deps.as_mut());
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                mint_msg("1"),
+            )
+            .unwrap();
+
+        let resp = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                mock_info(USER, &[]),
+                ExecuteMsg::Base(cw721_base::ExecuteMsg::SendNft {
+                    contract: RECEIVER.to_string(),
+                    token_id: "1".to_string(),
+                    msg: Binary::from(b"{}".to_vec()),
+                }),
+            )
+            .unwrap();
+        let attributes = &resp.response.attributes;
+        assert_eq!(attribute(attributes, "action"), "send_nft");
+        assert_eq!(attribute(attributes, "recipient"), RECEIVER);
+
+        // the receive hook still goes out to the receiving contract
+        assert_eq!(resp.response.messages.len(), 1);
+        match &resp.response.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => {
+                assert_eq!(contract_addr, RECEIVER)
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn typed_queries() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let mut tokens = setup(&mut deps.as_mut());
+        for token_id in ["1", "2"] {
+            tokens
+                .execute(
+                    &mut deps.as_mut(),
+                    env.clone(),
+                    mock_info(CREATOR, &[]),
+                    mint_msg(token_id),
+                )
+                .unwrap();
+        }
+
+        let resp = tokens
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::Base(cw721_base::QueryMsg::OwnerOf {
+                    token_id: "1".to_string(),
+                    include_expired: None,
+                }),
+            )
+            .unwrap();
+        assert_eq!(
+            resp,
+            QueryResp::OwnerOf(OwnerOfResponse {
+                owner: USER.to_string(),
+                approvals: vec![],
+            })
+        );
+
+        let resp = tokens
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::Base(cw721_base::QueryMsg::NftInfo {
+                    token_id: "2".to_string(),
+                }),
+            )
+            .unwrap();
+        assert_eq!(
+            resp,
+            QueryResp::NftInfo(NftInfoResponse {
+                token_uri: None,
+                extension: None,
+            })
+        );
+
+        let resp = tokens
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::Base(cw721_base::QueryMsg::NumTokens {}),
+            )
+            .unwrap();
+        assert_eq!(resp, QueryResp::NumTokens(NumTokensResponse { count: 2 }));
+
+        let resp = tokens
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::Base(cw721_base::QueryMsg::Tokens {
+                    owner: USER.to_string(),
+                    start_after: None,
+                    limit: None,
+                }),
+            )
+            .unwrap();
+        assert_eq!(
+            resp,
+            QueryResp::Tokens(TokensResponse {
+                tokens: vec!["1".to_string(), "2".to_string()],
+            })
+        );
+
+        let resp = tokens
+            .query(
+                &deps.as_ref(),
+                env,
+                QueryMsg::Base(cw721_base::QueryMsg::Minter {}),
+            )
+            .unwrap();
+        assert_eq!(
+            resp,
+            QueryResp::Minter(MinterResponse {
+                minter: CREATOR.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn royalties() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let mut tokens = setup(&mut deps.as_mut());
+        for token_id in ["1", "2"] {
+            tokens
+                .execute(
+                    &mut deps.as_mut(),
+                    env.clone(),
+                    mock_info(CREATOR, &[]),
+                    mint_msg(token_id),
+                )
+                .unwrap();
+        }
+
+        // only the minter sets royalties, capped at 100%
+        let msg = ExecuteMsg::Tokens(TokensExecuteMsg::SetCollectionRoyalty {
+            payment_address: Addr::unchecked(CREATOR),
+            basis_points: 500,
+        });
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                msg.clone(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::Unauthorized {}));
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                msg,
+            )
+            .unwrap();
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::SetTokenRoyalty {
+                    token_id: "2".to_string(),
+                    payment_address: Addr::unchecked(USER),
+                    basis_points: 10_001,
+                }),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::InvalidRoyalty { .. }));
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::SetTokenRoyalty {
+                    token_id: "2".to_string(),
+                    payment_address: Addr::unchecked(USER),
+                    basis_points: 1_000,
+                }),
+            )
+            .unwrap();
+
+        let royalty_info = |token_id: &str| {
+            tokens
+                .query(
+                    &deps.as_ref(),
+                    env.clone(),
+                    QueryMsg::Tokens(TokensQueryMsg::RoyaltyInfo {
+                        token_id: token_id.to_string(),
+                        sale_price: Uint128::new(1_000),
+                    }),
+                )
+                .unwrap()
+        };
+        // token 1 falls back to the collection royalty
+        assert_eq!(
+            royalty_info("1"),
+            QueryResp::RoyaltyInfo(RoyaltiesInfoResponse {
+                address: CREATOR.to_string(),
+                royalty_amount: Uint128::new(50),
+            })
+        );
+        assert_eq!(
+            royalty_info("2"),
+            QueryResp::RoyaltyInfo(RoyaltiesInfoResponse {
+                address: USER.to_string(),
+                royalty_amount: Uint128::new(100),
+            })
+        );
+
+        // a token minted again under a burnt id pays the collection royalty
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::Base(cw721_base::ExecuteMsg::Burn {
+                    token_id: "2".to_string(),
+                }),
+            )
+            .unwrap();
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                mock_info(CREATOR, &[]),
+                mint_msg("2"),
+            )
+            .unwrap();
+        assert_eq!(
+            tokens
+                .royalty_info(&deps.as_ref(), "2", Uint128::new(1_000))
+                .unwrap(),
+            RoyaltiesInfoResponse {
+                address: CREATOR.to_string(),
+                royalty_amount: Uint128::new(50),
+            }
+        );
+    }
+
+    #[test]
+    fn cw721_messages_keep_their_format() {
+        let msg: ExecuteMsg<Extension, Empty> =
+            serde_json::from_str(r#"{"transfer_nft":{"recipient":"someone","token_id":"1"}}"#)
+                .unwrap();
+        assert!(matches!(
+            msg,
+            ExecuteMsg::Base(cw721_base::ExecuteMsg::TransferNft { .. })
+        ));
+        let msg: QueryMsg<Empty> =
+            serde_json::from_str(r#"{"royalty_info":{"token_id":"1","sale_price":"100"}}"#)
+                .unwrap();
+        assert!(matches!(
+            msg,
+            QueryMsg::Tokens(TokensQueryMsg::RoyaltyInfo { .. })
+        ));
+    }
+
+    struct RecordingHook(Rc<RefCell<Vec<TokenEvent>>>);
+
+    impl TokenHook for RecordingHook {
+        fn on_token_event(&self, _deps: &mut DepsMut, event: &TokenEvent) -> StdResult<()> {
+            self.0.borrow_mut().push(event.clone());
+            Ok(())
+        }
+    }
+
+    fn is_transferable(tokens: &TestTokens, deps: &Deps, token_id: &str) -> QueryResp<Extension> {
+        tokens
+            .query(
+                deps,
+                mock_env(),
+                QueryMsg::Tokens(TokensQueryMsg::IsTransferable {
+                    token_id: token_id.to_string(),
+                }),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn soulbound_tokens() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let mut tokens = setup(&mut deps.as_mut());
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                mint_msg("1"),
+            )
+            .unwrap();
+
+        // flag a single token as soulbound at mint
+        let msg: ExecuteMsg<Extension, Empty> = serde_json::from_value(serde_json::json!({
+            "mint": {
+                "token_id": "2",
+                "owner": USER,
+                "token_uri": null,
+                "extension": null,
+                "soulbound": true,
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            msg,
+            ExecuteMsg::Tokens(TokensExecuteMsg::Mint(msg::MintMsg {
+                token_id: Some("2".to_string()),
+                owner: USER.to_string(),
+                token_uri: None,
+                extension: None,
+                soulbound: true,
+            }))
+        );
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                msg,
+            )
+            .unwrap();
+
+        assert_eq!(
+            is_transferable(&tokens, &deps.as_ref(), "1"),
+            QueryResp::IsTransferable(true)
+        );
+        assert_eq!(
+            is_transferable(&tokens, &deps.as_ref(), "2"),
+            QueryResp::IsTransferable(false)
+        );
+
+        let transfer = |token_id: &str| {
+            ExecuteMsg::Base(cw721_base::ExecuteMsg::TransferNft {
+                recipient: CREATOR.to_string(),
+                token_id: token_id.to_string(),
+            })
+        };
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                transfer("2"),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::Soulbound {}));
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::Base(cw721_base::ExecuteMsg::Approve {
+                    spender: CREATOR.to_string(),
+                    token_id: "2".to_string(),
+                    expires: None,
+                }),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::Soulbound {}));
+
+        // a token minted again under a burnt id isn't soulbound
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::BatchBurn {
+                    token_ids: vec!["2".to_string()],
+                }),
+            )
+            .unwrap();
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                mint_msg("2"),
+            )
+            .unwrap();
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                transfer("2"),
+            )
+            .unwrap();
+
+        // the whole collection
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::SetSoulbound { soulbound: true }),
+            )
+            .unwrap();
+        assert_eq!(
+            is_transferable(&tokens, &deps.as_ref(), "1"),
+            QueryResp::IsTransferable(false)
+        );
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                transfer("1"),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::Soulbound {}));
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                mock_info(USER, &[]),
+                ExecuteMsg::Base(cw721_base::ExecuteMsg::ApproveAll {
+                    operator: CREATOR.to_string(),
+                    expires: None,
+                }),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::Soulbound {}));
+    }
+
+    #[test]
+    fn hooks_see_transfers_sends_and_burns() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let mut tokens = setup(&mut deps.as_mut());
+        let events = Rc::new(RefCell::new(vec![]));
+        tokens.add_hook(RecordingHook(events.clone()));
+
+        for token_id in ["1", "2", "3"] {
+            tokens
+                .execute(
+                    &mut deps.as_mut(),
+                    env.clone(),
+                    mock_info(CREATOR, &[]),
+                    mint_msg(token_id),
+                )
+                .unwrap();
+        }
+        let msgs = [
+            cw721_base::ExecuteMsg::TransferNft {
+                recipient: CREATOR.to_string(),
+                token_id: "1".to_string(),
+            },
+            cw721_base::ExecuteMsg::SendNft {
+                contract: RECEIVER.to_string(),
+                token_id: "2".to_string(),
+                msg: Binary::default(),
+            },
+            cw721_base::ExecuteMsg::Burn {
+                token_id: "3".to_string(),
+            },
+        ];
+        for msg in msgs {
+            tokens
+                .execute(
+                    &mut deps.as_mut(),
+                    env.clone(),
+                    mock_info(USER, &[]),
+                    ExecuteMsg::Base(msg),
+                )
+                .unwrap();
+        }
+        // failed messages don't reach the hooks
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                mock_info(USER, &[]),
+                ExecuteMsg::Base(cw721_base::ExecuteMsg::Burn {
+                    token_id: "1".to_string(),
+                }),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                TokenEvent::Transfer {
+                    token_id: "1".to_string(),
+                    from: Addr::unchecked(USER),
+                    to: Addr::unchecked(CREATOR),
+                },
+                TokenEvent::Send {
+                    token_id: "2".to_string(),
+                    from: Addr::unchecked(USER),
+                    contract: Addr::unchecked(RECEIVER),
+                },
+                TokenEvent::Burn {
+                    token_id: "3".to_string(),
+                    owner: Addr::unchecked(USER),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn batches() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let mut tokens = setup(&mut deps.as_mut());
+
+        let mints = ["1", "2", "3"]
+            .iter()
+            .map(|token_id| msg::MintMsg {
+                token_id: Some(token_id.to_string()),
+                owner: USER.to_string(),
+                token_uri: None,
+                extension: None,
+                soulbound: false,
+            })
+            .collect::<Vec<_>>();
+        // only the minter can mint
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::BatchMint {
+                    mints: mints.clone(),
+                }),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::Unauthorized {}));
+        let res = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::BatchMint { mints }),
+            )
+            .unwrap();
+        assert_eq!(attribute(&res.response.attributes, "action"), "batch_mint");
+        assert_eq!(attribute(&res.response.attributes, "count"), "3");
+        assert_eq!(res.response.events.len(), 3);
+        assert_eq!(res.response.events[1].ty, "mint");
+        assert_eq!(
+            attribute(&res.response.events[1].attributes, "token_id"),
+            "2"
+        );
+        assert_eq!(attribute(&res.response.events[1].attributes, "owner"), USER);
+
+        let res = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::BatchTransfer {
+                    transfers: vec![
+                        msg::Transfer {
+                            recipient: CREATOR.to_string(),
+                            token_id: "1".to_string(),
+                        },
+                        msg::Transfer {
+                            recipient: RECEIVER.to_string(),
+                            token_id: "2".to_string(),
+                        },
+                    ],
+                }),
+            )
+            .unwrap();
+        assert_eq!(res.response.events.len(), 2);
+        assert_eq!(res.response.events[1].ty, "transfer_nft");
+        assert_eq!(
+            attribute(&res.response.events[1].attributes, "recipient"),
+            RECEIVER
+        );
+        let owner = tokens
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::Base(cw721_base::QueryMsg::OwnerOf {
+                    token_id: "2".to_string(),
+                    include_expired: None,
+                }),
+            )
+            .unwrap();
+        match owner {
+            QueryResp::OwnerOf(owner) => assert_eq!(owner.owner, RECEIVER),
+            _ => panic!(),
+        }
+
+        // USER no longer owns token 1, so the whole burn errors
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::BatchBurn {
+                    token_ids: vec!["3".to_string(), "1".to_string()],
+                }),
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TokenError::Base(cw721_base::ContractError::Unauthorized {})
+        ));
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::BatchBurn {
+                    token_ids: vec!["3".to_string(), "3".to_string()],
+                }),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::DuplicateToken { .. }));
+        let res = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::BatchBurn {
+                    token_ids: vec!["3".to_string()],
+                }),
+            )
+            .unwrap();
+        assert_eq!(res.response.events[0].ty, "burn");
+
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                mock_info(USER, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::BatchBurn { token_ids: vec![] }),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::EmptyBatch {}));
+    }
+
+    // generated_mint leaves out the token_id, making it a Tokens mint
+    fn generated_mint() -> ExecuteMsg<Extension, Empty> {
+        serde_json::from_value(serde_json::json!({
+            "mint": { "owner": USER, "token_uri": null, "extension": null }
+        }))
+        .unwrap()
+    }
+
+    fn mint_generated(tokens: &mut TestTokens, deps: &mut DepsMut) -> String {
+        let res = tokens
+            .execute(deps, mock_env(), mock_info(CREATOR, &[]), generated_mint())
+            .unwrap();
+        attribute(&res.response.attributes, "token_id")
+    }
+
+    fn set_generator(
+        tokens: &mut TestTokens,
+        deps: &mut DepsMut,
+        generator: TokenIdGenerator,
+    ) -> Result<Response, TokenError> {
+        tokens.execute(
+            deps,
+            mock_env(),
+            mock_info(CREATOR, &[]),
+            ExecuteMsg::Tokens(TokensExecuteMsg::SetTokenIdGenerator { generator }),
+        )
+    }
+
+    #[test]
+    fn generated_token_ids() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let mut tokens = setup(&mut deps.as_mut());
+
+        assert_eq!(mint_generated(&mut tokens, &mut deps.as_mut()), "1");
+
+        // ids minted by hand are skipped
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                mint_msg("2"),
+            )
+            .unwrap();
+        assert_eq!(mint_generated(&mut tokens, &mut deps.as_mut()), "3");
+
+        set_generator(
+            &mut tokens,
+            &mut deps.as_mut(),
+            TokenIdGenerator::Counter {
+                prefix: Some("seat-".to_string()),
+                padding: Some(5),
+            },
+        )
+        .unwrap();
+        let next = tokens
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::Tokens(TokensQueryMsg::NextTokenId {}),
+            )
+            .unwrap();
+        assert_eq!(next, QueryResp::NextTokenId("seat-00004".to_string()));
+        assert_eq!(
+            mint_generated(&mut tokens, &mut deps.as_mut()),
+            "seat-00004"
+        );
+
+        let err = set_generator(
+            &mut tokens,
+            &mut deps.as_mut(),
+            TokenIdGenerator::Pattern {
+                template: "vip".to_string(),
+                padding: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, TokenError::InvalidTokenIdPattern { .. }));
+        set_generator(
+            &mut tokens,
+            &mut deps.as_mut(),
+            TokenIdGenerator::Pattern {
+                template: "vip-{id}-row-a".to_string(),
+                padding: Some(3),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            mint_generated(&mut tokens, &mut deps.as_mut()),
+            "vip-005-row-a"
+        );
+
+        // only the minter can mint with a generated id
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                mock_info(USER, &[]),
+                generated_mint(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::Unauthorized {}));
+    }
+
+    #[test]
+    fn token_updates() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let mut tokens = setup(&mut deps.as_mut());
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                mint_msg("1"),
+            )
+            .unwrap();
+
+        let update_uri = |token_uri: &str| {
+            ExecuteMsg::Tokens(TokensExecuteMsg::UpdateTokenUri {
+                token_id: "1".to_string(),
+                token_uri: Some(token_uri.to_string()),
+            })
+        };
+        // only the minter can update, not even the holder
+        let res = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                update_uri("ipfs://venue-a"),
+            )
+            .unwrap();
+        assert_eq!(res.response.events[0].ty, "update_token_uri");
+        assert_eq!(
+            attribute(&res.response.events[0].attributes, "token_uri"),
+            "ipfs://venue-a"
+        );
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                update_uri("ipfs://venue-b"),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::Unauthorized {}));
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(RECEIVER, &[]),
+                update_uri("ipfs://venue-c"),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::Unauthorized {}));
+
+        let res = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::UpdateExtension {
+                    token_id: "1".to_string(),
+                    extension: Some(Empty {}),
+                }),
+            )
+            .unwrap();
+        assert_eq!(res.response.events[0].ty, "update_extension");
+        let info = tokens
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::Base(cw721_base::QueryMsg::NftInfo {
+                    token_id: "1".to_string(),
+                }),
+            )
+            .unwrap();
+        assert_eq!(
+            info,
+            QueryResp::NftInfo(NftInfoResponse {
+                token_uri: Some("ipfs://venue-a".to_string()),
+                extension: Some(Empty {}),
+            })
+        );
+
+        // once locked the token can't be updated anymore, the holder can't
+        // lock it against the issuer's corrections
+        let lock = ExecuteMsg::Tokens(TokensExecuteMsg::LockToken {
+            token_id: "1".to_string(),
+        });
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                lock.clone(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::Unauthorized {}));
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                lock,
+            )
+            .unwrap();
+        let locked = tokens
+            .query(
+                &deps.as_ref(),
+                env.clone(),
+                QueryMsg::Tokens(TokensQueryMsg::IsLocked {
+                    token_id: "1".to_string(),
+                }),
+            )
+            .unwrap();
+        assert_eq!(locked, QueryResp::IsLocked(true));
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                update_uri("ipfs://venue-c"),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::TokenLocked { .. }));
+
+        // a token minted again under a burnt id isn't locked
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                ExecuteMsg::Base(cw721_base::ExecuteMsg::Burn {
+                    token_id: "1".to_string(),
+                }),
+            )
+            .unwrap();
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &[]),
+                mint_msg("1"),
+            )
+            .unwrap();
+        assert!(!tokens.is_locked(&deps.as_ref(), "1").unwrap());
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env,
+                mock_info(CREATOR, &[]),
+                update_uri("ipfs://venue-c"),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn minter_role() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let mut tokens = setup(&mut deps.as_mut());
+
+        let err = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(RECEIVER, &[]),
+                mint_msg("1"),
+            )
+            .unwrap_err();
+        assert!(matches!(err, TokenError::Unauthorized {}));
+
+        // minter role holders mint and manage the collection like the minter
+        Roles::default()
+            .grant_role(&mut deps.as_mut(), Role::Minter, &Addr::unchecked(RECEIVER))
+            .unwrap();
+        let res = tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(RECEIVER, &[]),
+                mint_msg("1"),
+            )
+            .unwrap();
+        assert_eq!(attribute(&res.response.attributes, "minter"), RECEIVER);
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(RECEIVER, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::Mint(msg::MintMsg {
+                    token_id: None,
+                    owner: USER.to_string(),
+                    token_uri: None,
+                    extension: None,
+                    soulbound: false,
+                })),
+            )
+            .unwrap();
+        tokens
+            .execute(
+                &mut deps.as_mut(),
+                env.clone(),
+                mock_info(RECEIVER, &[]),
+                ExecuteMsg::Tokens(TokensExecuteMsg::UpdateTokenUri {
+                    token_id: "1".to_string(),
+                    token_uri: Some("ipfs://venue-a".to_string()),
+                }),
+            )
+            .unwrap();
+        let num_tokens = tokens
+            .query(
+                &deps.as_ref(),
+                env,
+                QueryMsg::Base(cw721_base::QueryMsg::NumTokens {}),
+            )
+            .unwrap();
+        assert_eq!(
+            num_tokens,
+            QueryResp::NumTokens(NumTokensResponse { count: 2 })
+        );
+    }
+}
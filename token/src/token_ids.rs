@@ -0,0 +1,117 @@
+use cosmwasm_std::{CustomMsg, Deps, DepsMut, StdResult};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::TokenError;
+use crate::Tokens;
+
+pub const TOKEN_ID_GENERATOR: Item<TokenIdGenerator> = Item::new("token_id_generator");
+pub const TOKEN_ID_COUNTER: Item<u64> = Item::new("token_id_counter");
+
+const ID_PLACEHOLDER: &str = "{id}";
+
+// TokenIdGenerator formats the ids given to tokens minted without one, both
+// variants count up from 1
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenIdGenerator {
+    // Counter gives "<prefix><counter>", the counter zero padded to `padding`
+    // digits
+    Counter {
+        prefix: Option<String>,
+        padding: Option<u8>,
+    },
+    // Pattern replaces every "{id}" of the template with the counter, zero
+    // padded to `padding` digits, e.g. "seat-{id}"
+    Pattern {
+        template: String,
+        padding: Option<u8>,
+    },
+}
+
+impl Default for TokenIdGenerator {
+    fn default() -> Self {
+        TokenIdGenerator::Counter {
+            prefix: None,
+            padding: None,
+        }
+    }
+}
+
+impl TokenIdGenerator {
+    pub fn format(&self, counter: u64) -> String {
+        match self {
+            TokenIdGenerator::Counter { prefix, padding } => format!(
+                "{}{}",
+                prefix.as_deref().unwrap_or_default(),
+                pad(counter, *padding)
+            ),
+            TokenIdGenerator::Pattern { template, padding } => {
+                template.replace(ID_PLACEHOLDER, &pad(counter, *padding))
+            }
+        }
+    }
+}
+
+fn pad(counter: u64, padding: Option<u8>) -> String {
+    format!("{:0width$}", counter, width = padding.unwrap_or(0) as usize)
+}
+
+impl<'a, T, C, E, Q> Tokens<'a, T, C, E, Q>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    Q: CustomMsg,
+    E: CustomMsg,
+    C: CustomMsg,
+{
+    pub fn token_id_generator(&self, deps: &Deps) -> StdResult<TokenIdGenerator> {
+        Ok(self
+            .token_id_generator
+            .may_load(deps.storage)?
+            .unwrap_or_default())
+    }
+
+    pub fn set_token_id_generator(
+        &self,
+        deps: &mut DepsMut,
+        generator: &TokenIdGenerator,
+    ) -> Result<(), TokenError> {
+        if let TokenIdGenerator::Pattern { template, .. } = generator {
+            // without the placeholder every token would get the same id
+            if !template.contains(ID_PLACEHOLDER) {
+                return Err(TokenError::InvalidTokenIdPattern {
+                    template: template.clone(),
+                });
+            }
+        }
+        self.token_id_generator.save(deps.storage, generator)?;
+        Ok(())
+    }
+
+    // peek_token_id returns the id the next token minted without one gets
+    pub fn peek_token_id(&self, deps: &Deps) -> StdResult<String> {
+        Ok(self.find_token_id(deps)?.1)
+    }
+
+    // next_token_id claims the next free id, skipping ids already minted by
+    // hand so generated ids never collide
+    pub fn next_token_id(&self, deps: &mut DepsMut) -> StdResult<String> {
+        let (counter, token_id) = self.find_token_id(&deps.as_ref())?;
+        self.token_id_counter.save(deps.storage, &counter)?;
+        Ok(token_id)
+    }
+
+    fn find_token_id(&self, deps: &Deps) -> StdResult<(u64, String)> {
+        let generator = self.token_id_generator(deps)?;
+        let mut counter = self.token_id_counter.may_load(deps.storage)?.unwrap_or(0);
+        loop {
+            counter += 1;
+            let token_id = generator.format(counter);
+            if !self.contract.tokens.has(deps.storage, &token_id) {
+                return Ok((counter, token_id));
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use cosmwasm_std::{Addr, CustomMsg, Deps, DepsMut, Event, StdResult};
+use cw721_base::state::TokenInfo;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::TokenError;
+use crate::Tokens;
+
+impl<'a, T, C, E, Q> Tokens<'a, T, C, E, Q>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    Q: CustomMsg,
+    E: CustomMsg,
+    C: CustomMsg,
+{
+    pub fn is_locked(&self, deps: &Deps, token_id: &str) -> StdResult<bool> {
+        Ok(self.locked_tokens.has(deps.storage, token_id))
+    }
+
+    // lock_token stops any further update of the token's uri and extension,
+    // a lock can't be lifted
+    pub fn lock_token(
+        &self,
+        deps: &mut DepsMut,
+        sender: &Addr,
+        token_id: &str,
+    ) -> Result<Event, TokenError> {
+        self.check_can_update(&deps.as_ref(), sender, token_id)?;
+        self.locked_tokens.save(deps.storage, token_id, &true)?;
+        Ok(Event::new("lock_token")
+            .add_attribute("sender", sender)
+            .add_attribute("token_id", token_id))
+    }
+
+    pub fn update_token_uri(
+        &self,
+        deps: &mut DepsMut,
+        sender: &Addr,
+        token_id: &str,
+        token_uri: Option<String>,
+    ) -> Result<Event, TokenError> {
+        let mut token = self.check_can_update(&deps.as_ref(), sender, token_id)?;
+        token.token_uri = token_uri.clone();
+        self.contract.tokens.save(deps.storage, token_id, &token)?;
+        Ok(Event::new("update_token_uri")
+            .add_attribute("sender", sender)
+            .add_attribute("token_id", token_id)
+            .add_attribute("token_uri", token_uri.unwrap_or_default()))
+    }
+
+    pub fn update_extension(
+        &self,
+        deps: &mut DepsMut,
+        sender: &Addr,
+        token_id: &str,
+        extension: T,
+    ) -> Result<Event, TokenError> {
+        let mut token = self.check_can_update(&deps.as_ref(), sender, token_id)?;
+        token.extension = extension;
+        self.contract.tokens.save(deps.storage, token_id, &token)?;
+        Ok(Event::new("update_extension")
+            .add_attribute("sender", sender)
+            .add_attribute("token_id", token_id))
+    }
+
+    // check_can_update lets the issuer change an unlocked token, holders
+    // can't rewrite what they were issued
+    fn check_can_update(
+        &self,
+        deps: &Deps,
+        sender: &Addr,
+        token_id: &str,
+    ) -> Result<TokenInfo<T>, TokenError> {
+        self.check_minter(deps, sender)?;
+        let token = self.contract.tokens.load(deps.storage, token_id)?;
+        if self.is_locked(deps, token_id)? {
+            return Err(TokenError::TokenLocked {
+                token_id: token_id.to_string(),
+            });
+        }
+        Ok(token)
+    }
+}